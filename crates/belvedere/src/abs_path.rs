@@ -0,0 +1,86 @@
+//! A type-safe absolute path, modeled on rust-analyzer's `AbsPathBuf`. A
+//! plain `PathBuf` doesn't distinguish an absolute path from a relative
+//! one, which made deduplicating agents discovered via two different
+//! roots (e.g. standalone vs in-rig) unreliable when the same agent was
+//! reachable by two differently-spelled paths.
+
+use std::path::{Component, Path, PathBuf};
+
+/// An owned, absolute path. Construct via [`try_from`](Self::try_from);
+/// there is no way to build one from a relative `PathBuf`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AbsPathBuf(PathBuf);
+
+impl AbsPathBuf {
+    /// Wraps `path` if it's absolute, returning it back unchanged
+    /// otherwise.
+    pub fn try_from(path: PathBuf) -> Result<Self, PathBuf> {
+        if path.is_absolute() {
+            Ok(Self(path))
+        } else {
+            Err(path)
+        }
+    }
+
+    /// Resolves `.`/`..` components lexically, then canonicalizes through
+    /// symlinks if the path exists. Falls back to the lexical result for a
+    /// path that doesn't exist (or can't be read), since canonicalization
+    /// requires the path to be present on disk.
+    pub fn normalize(&self) -> Self {
+        let lexical = normalize_lexically(&self.0);
+        match std::fs::canonicalize(&lexical) {
+            Ok(canonical) => Self(canonical),
+            Err(_) => Self(lexical),
+        }
+    }
+
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0
+    }
+}
+
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                result.pop();
+            }
+            Component::CurDir => {}
+            other => result.push(other.as_os_str()),
+        }
+    }
+    result
+}
+
+impl AsRef<Path> for AbsPathBuf {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for AbsPathBuf {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl PartialEq<Path> for AbsPathBuf {
+    fn eq(&self, other: &Path) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<PathBuf> for AbsPathBuf {
+    fn eq(&self, other: &PathBuf) -> bool {
+        &self.0 == other
+    }
+}
+
+impl From<AbsPathBuf> for PathBuf {
+    fn from(path: AbsPathBuf) -> PathBuf {
+        path.0
+    }
+}
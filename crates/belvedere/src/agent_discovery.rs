@@ -1,7 +1,18 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::Duration;
+
+use notify::event::{ModifyKind, RenameMode};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::abs_path::AbsPathBuf;
+use crate::config::DiscoveryConfig;
 
 /// Agent roles in the Gas Town ecosystem
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AgentRole {
     /// Primary AI coordinator
     Mayor,
@@ -18,9 +29,23 @@ pub enum AgentRole {
 }
 
 impl AgentRole {
-    /// Parse agent role from a directory name component
+    /// Parse agent role from a directory name component, using only the
+    /// built-in prefixes.
     fn from_name(name: &str) -> Self {
-        match name.to_lowercase().as_str() {
+        Self::from_name_with_overrides(name, &HashMap::new())
+    }
+
+    /// Parse agent role from a directory name component, consulting
+    /// `extra_prefixes` (from a `belvedere.toml`'s `role_prefixes`) before
+    /// falling back to the built-in prefixes.
+    fn from_name_with_overrides(name: &str, extra_prefixes: &HashMap<String, AgentRole>) -> Self {
+        let lower = name.to_lowercase();
+
+        if let Some(role) = extra_prefixes.get(&lower) {
+            return *role;
+        }
+
+        match lower.as_str() {
             "mayor" => AgentRole::Mayor,
             "polecat" => AgentRole::Polecat,
             "crew" => AgentRole::Crew,
@@ -47,8 +72,8 @@ impl std::fmt::Display for AgentRole {
 /// Represents a discovered agent directory
 #[derive(Debug, Clone, PartialEq)]
 pub struct AgentDirectory {
-    /// Full path to the agent directory
-    pub path: PathBuf,
+    /// Absolute path to the agent directory
+    pub path: AbsPathBuf,
     /// The agent's role type
     pub role: AgentRole,
     /// The full instance name (e.g., "polecat-1", "mayor", "crew-alice")
@@ -66,13 +91,22 @@ impl AgentDirectory {
     /// - `polecat-1` → Polecat role, instance "1"
     /// - `crew-alice` → Crew role, instance "alice"
     /// - `witness-backend` → Witness role, instance "backend"
-    pub fn from_path(path: PathBuf) -> Option<Self> {
+    pub fn from_path(path: AbsPathBuf) -> Option<Self> {
+        Self::from_path_with_overrides(path, &HashMap::new())
+    }
+
+    /// Like [`from_path`](Self::from_path), but consults `extra_prefixes`
+    /// (a `belvedere.toml`'s `role_prefixes`) before the built-in ones.
+    fn from_path_with_overrides(
+        path: AbsPathBuf,
+        extra_prefixes: &HashMap<String, AgentRole>,
+    ) -> Option<Self> {
         let dir_name = path.file_name()?.to_str()?.to_string();
 
         // Split on first hyphen to separate role from instance
         let parts: Vec<&str> = dir_name.splitn(2, '-').collect();
 
-        let role = AgentRole::from_name(parts[0]);
+        let role = AgentRole::from_name_with_overrides(parts[0], extra_prefixes);
         let instance_id = parts.get(1).map(|s| s.to_string());
 
         Some(AgentDirectory {
@@ -84,34 +118,111 @@ impl AgentDirectory {
     }
 }
 
+/// A directory's discovered agents appearing, disappearing, or being
+/// renamed, as reported by [`AgentDiscovery::watch`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgentEvent {
+    Added(AgentDirectory),
+    Removed(PathBuf),
+    Renamed { from: PathBuf, to: AgentDirectory },
+}
+
+/// Per-directory cached scan results, so repeated `discover_agents` calls
+/// don't re-`read_dir` every standalone/rig agents directory. Keyed by the
+/// agents directory path (not the individual agent path), so a watch event
+/// on one rig only invalidates that rig's entry.
+type DiscoveryCache = OnceLock<RwLock<HashMap<PathBuf, Vec<AgentDirectory>>>>;
+
 /// Discovers agent directories from known locations
 pub struct AgentDiscovery {
     /// Root directory for Gas Town (e.g., ~/gt/)
     gastown_root: Option<PathBuf>,
+    /// `belvedere.toml`, and the directory it was found in, if any. When
+    /// absent, discovery falls back to the hardcoded locations.
+    config: Option<(PathBuf, DiscoveryConfig)>,
+    cache: DiscoveryCache,
 }
 
 impl AgentDiscovery {
-    /// Create a new agent discovery instance
+    /// Create a new agent discovery instance, loading `belvedere.toml`
+    /// from `gastown_root` upward if one is present.
     pub fn new(gastown_root: Option<PathBuf>) -> Self {
-        Self { gastown_root }
+        let config = gastown_root
+            .as_deref()
+            .and_then(DiscoveryConfig::find);
+
+        Self {
+            gastown_root,
+            config,
+            cache: OnceLock::new(),
+        }
+    }
+
+    fn cache(&self) -> &RwLock<HashMap<PathBuf, Vec<AgentDirectory>>> {
+        self.cache.get_or_init(|| RwLock::new(HashMap::new()))
+    }
+
+    /// Drops the cached scan result for `agents_dir`, if any, so the next
+    /// lookup re-reads the directory from disk.
+    fn invalidate(&self, agents_dir: &Path) {
+        self.cache().write().unwrap().remove(agents_dir);
     }
 
     /// Discover all agent directories
     ///
-    /// Scans:
+    /// With a `belvedere.toml` present, follows its `agents`/`rig_roots`
+    /// glob patterns. Otherwise scans the hardcoded locations:
     /// - Standalone agents: `~/.gazetown/agents/`
     /// - In-rig agents: `<rig>/.agents/`
+    ///
+    /// An agent reachable through two routes (e.g. both a standalone
+    /// listing and an in-rig scan) collapses to a single entry, keyed by
+    /// its normalized, canonicalized path.
     pub fn discover_agents(&self) -> Vec<AgentDirectory> {
+        let agents = if let Some((base, config)) = self.config.as_ref() {
+            self.discover_configured_agents(base, config)
+        } else {
+            let mut agents = Vec::new();
+
+            // Discover standalone agents
+            if let Some(standalone) = self.discover_standalone_agents() {
+                agents.extend(standalone);
+            }
+
+            // Discover in-rig agents
+            if let Some(rig_agents) = self.discover_rig_agents() {
+                agents.extend(rig_agents);
+            }
+
+            agents
+        };
+
+        dedupe_by_canonical_path(agents)
+    }
+
+    /// Discovers agents via `config`'s glob patterns, resolved relative
+    /// to `base` (the directory `belvedere.toml` was found in).
+    fn discover_configured_agents(&self, base: &Path, config: &DiscoveryConfig) -> Vec<AgentDirectory> {
         let mut agents = Vec::new();
 
-        // Discover standalone agents
-        if let Some(standalone) = self.discover_standalone_agents() {
-            agents.extend(standalone);
+        for agent_dir in config.matched_agent_dirs(base) {
+            let Ok(agent_dir) = AbsPathBuf::try_from(agent_dir) else {
+                continue;
+            };
+            if let Some(agent) =
+                AgentDirectory::from_path_with_overrides(agent_dir, &config.role_prefixes)
+            {
+                if agent.role != AgentRole::Unknown {
+                    agents.push(agent);
+                }
+            }
         }
 
-        // Discover in-rig agents
-        if let Some(rig_agents) = self.discover_rig_agents() {
-            agents.extend(rig_agents);
+        for rig_root in config.matched_rig_roots(base) {
+            let agents_dir = rig_root.join(".agents");
+            if agents_dir.exists() {
+                agents.extend(self.scan_agents_directory(&agents_dir));
+            }
         }
 
         agents
@@ -156,15 +267,31 @@ impl AgentDiscovery {
         Some(agents)
     }
 
-    /// Scan a specific agents directory for agent subdirectories
+    /// Scan a specific agents directory for agent subdirectories, serving
+    /// a cached result when one is present for `agents_dir`.
     fn scan_agents_directory(&self, agents_dir: &Path) -> Vec<AgentDirectory> {
+        if let Some(cached) = self.cache().read().unwrap().get(agents_dir) {
+            return cached.clone();
+        }
+
+        let agents = self.scan_agents_directory_uncached(agents_dir);
+        self.cache()
+            .write()
+            .unwrap()
+            .insert(agents_dir.to_path_buf(), agents.clone());
+        agents
+    }
+
+    /// Scans `agents_dir` for agent subdirectories, always hitting disk.
+    fn scan_agents_directory_uncached(&self, agents_dir: &Path) -> Vec<AgentDirectory> {
         let mut agents = Vec::new();
 
         if let Ok(entries) = std::fs::read_dir(agents_dir) {
             for entry in entries.flatten() {
                 if let Ok(metadata) = entry.metadata() {
                     if metadata.is_dir() {
-                        if let Some(agent) = AgentDirectory::from_path(entry.path()) {
+                        let path = AbsPathBuf::try_from(entry.path()).ok();
+                        if let Some(agent) = path.and_then(AgentDirectory::from_path) {
                             // Filter out unknown roles unless we want to keep them for debugging
                             if agent.role != AgentRole::Unknown {
                                 agents.push(agent);
@@ -177,6 +304,187 @@ impl AgentDiscovery {
 
         agents
     }
+
+    /// Lists the agents directories this instance watches: the standalone
+    /// `~/.gazetown/agents` directory, plus every rig's `.agents` under
+    /// `gastown_root`.
+    fn watched_agents_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+
+        if let Some(home) = dirs::home_dir() {
+            let standalone = home.join(".gazetown").join("agents");
+            if standalone.exists() {
+                dirs.push(standalone);
+            }
+        }
+
+        if let Some(root) = self.gastown_root.as_ref() {
+            if let Ok(entries) = std::fs::read_dir(root) {
+                for entry in entries.flatten() {
+                    if let Ok(metadata) = entry.metadata() {
+                        if metadata.is_dir() {
+                            let agents_dir = entry.path().join(".agents");
+                            if agents_dir.exists() {
+                                dirs.push(agents_dir);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        dirs
+    }
+
+    /// Watches every known agents directory for create/remove/rename
+    /// events, debounced by ~100ms, and invokes `on_event` with the
+    /// resulting [`AgentEvent`]s. Only the affected directory's cache
+    /// entry is invalidated and re-scanned, not the whole tree.
+    ///
+    /// Returns the underlying watcher; dropping it stops watching.
+    pub fn watch(
+        self: &Arc<Self>,
+        mut on_event: impl FnMut(AgentEvent) + Send + 'static,
+    ) -> notify::Result<RecommendedWatcher> {
+        const DEBOUNCE: Duration = Duration::from_millis(100);
+
+        let (tx, rx) = channel();
+        let mut watcher = RecommendedWatcher::new(tx, notify::Config::default())?;
+
+        for dir in self.watched_agents_dirs() {
+            watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
+
+        let discovery = Arc::clone(self);
+        let pending: Arc<Mutex<std::collections::HashSet<PathBuf>>> =
+            Arc::new(Mutex::new(std::collections::HashSet::new()));
+        // Rename pairs observed directly from the OS (`notify`'s
+        // `RenameMode::Both`), keyed by agents directory, and consumed by
+        // the next debounced scan of that directory. Full before/after
+        // directory scans can't tell a rename from a remove+add on their
+        // own, since an `AgentDirectory`'s fields are entirely determined
+        // by its path.
+        let pending_renames: Arc<Mutex<HashMap<PathBuf, Vec<(PathBuf, PathBuf)>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+
+        std::thread::spawn(move || {
+            while let Ok(Ok(event)) = rx.recv() {
+                let Some(agents_dir) = event
+                    .paths
+                    .first()
+                    .and_then(|p| p.parent())
+                    .map(|p| p.to_path_buf())
+                else {
+                    continue;
+                };
+
+                if let (EventKind::Modify(ModifyKind::Name(RenameMode::Both)), [from, to]) =
+                    (&event.kind, event.paths.as_slice())
+                {
+                    pending_renames
+                        .lock()
+                        .unwrap()
+                        .entry(agents_dir.clone())
+                        .or_default()
+                        .push((from.clone(), to.clone()));
+                }
+
+                {
+                    let mut pending = pending.lock().unwrap();
+                    if !pending.insert(agents_dir.clone()) {
+                        continue;
+                    }
+                }
+
+                std::thread::sleep(DEBOUNCE);
+
+                let before = discovery
+                    .cache()
+                    .read()
+                    .unwrap()
+                    .get(&agents_dir)
+                    .cloned()
+                    .unwrap_or_default();
+                discovery.invalidate(&agents_dir);
+                let after = discovery.scan_agents_directory(&agents_dir);
+                let renames = pending_renames
+                    .lock()
+                    .unwrap()
+                    .remove(&agents_dir)
+                    .unwrap_or_default();
+
+                for event in diff_agent_lists(&before, &after, &renames) {
+                    on_event(event);
+                }
+
+                pending.lock().unwrap().remove(&agents_dir);
+            }
+        });
+
+        Ok(watcher)
+    }
+}
+
+/// Collapses agents that resolve to the same normalized, canonicalized
+/// path to a single entry, keeping the first occurrence.
+fn dedupe_by_canonical_path(agents: Vec<AgentDirectory>) -> Vec<AgentDirectory> {
+    let mut seen = std::collections::HashSet::new();
+    agents
+        .into_iter()
+        .filter(|agent| seen.insert(agent.path.normalize()))
+        .collect()
+}
+
+/// Diffs two scans of the same agents directory by path, reporting
+/// additions and removals, with `renames` (OS-reported rename pairs
+/// collected between the two scans) used to report a matching pair as a
+/// single [`AgentEvent::Renamed`] instead.
+///
+/// Path equality alone can't distinguish a rename from a remove+add: every
+/// field of [`AgentDirectory`] is a pure function of its path, so two
+/// entries at the same path are always identical, and a renamed directory
+/// necessarily shows up at a different path in `after`.
+fn diff_agent_lists(
+    before: &[AgentDirectory],
+    after: &[AgentDirectory],
+    renames: &[(PathBuf, PathBuf)],
+) -> Vec<AgentEvent> {
+    let mut events = Vec::new();
+    let mut renamed_from = std::collections::HashSet::new();
+    let mut renamed_to = std::collections::HashSet::new();
+
+    for (from, to) in renames {
+        let old = before.iter().find(|old| old.path == *from);
+        let new = after.iter().find(|new| new.path == *to);
+        if let (Some(old), Some(new)) = (old, new) {
+            events.push(AgentEvent::Renamed {
+                from: old.path.clone().into_path_buf(),
+                to: new.clone(),
+            });
+            renamed_from.insert(old.path.clone().into_path_buf());
+            renamed_to.insert(new.path.clone().into_path_buf());
+        }
+    }
+
+    for old in before {
+        if renamed_from.contains(&old.path.clone().into_path_buf()) {
+            continue;
+        }
+        if !after.iter().any(|new| new.path == old.path) {
+            events.push(AgentEvent::Removed(old.path.clone().into_path_buf()));
+        }
+    }
+
+    for new in after {
+        if renamed_to.contains(&new.path.clone().into_path_buf()) {
+            continue;
+        }
+        if !before.iter().any(|old| old.path == new.path) {
+            events.push(AgentEvent::Added(new.clone()));
+        }
+    }
+
+    events
 }
 
 #[cfg(test)]
@@ -197,7 +505,7 @@ mod tests {
     #[test]
     fn test_agent_directory_from_path_mayor() {
         let path = PathBuf::from("/home/user/.gazetown/agents/mayor");
-        let agent = AgentDirectory::from_path(path.clone()).unwrap();
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(path.clone()).unwrap()).unwrap();
 
         assert_eq!(agent.path, path);
         assert_eq!(agent.role, AgentRole::Mayor);
@@ -208,7 +516,7 @@ mod tests {
     #[test]
     fn test_agent_directory_from_path_polecat_with_instance() {
         let path = PathBuf::from("/rig/.agents/polecat-1");
-        let agent = AgentDirectory::from_path(path.clone()).unwrap();
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(path.clone()).unwrap()).unwrap();
 
         assert_eq!(agent.path, path);
         assert_eq!(agent.role, AgentRole::Polecat);
@@ -219,7 +527,7 @@ mod tests {
     #[test]
     fn test_agent_directory_from_path_crew_with_name() {
         let path = PathBuf::from("/rig/.agents/crew-alice");
-        let agent = AgentDirectory::from_path(path.clone()).unwrap();
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(path.clone()).unwrap()).unwrap();
 
         assert_eq!(agent.path, path);
         assert_eq!(agent.role, AgentRole::Crew);
@@ -230,7 +538,7 @@ mod tests {
     #[test]
     fn test_agent_directory_from_path_witness_with_context() {
         let path = PathBuf::from("/rig/.agents/witness-backend");
-        let agent = AgentDirectory::from_path(path.clone()).unwrap();
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(path.clone()).unwrap()).unwrap();
 
         assert_eq!(agent.path, path);
         assert_eq!(agent.role, AgentRole::Witness);
@@ -241,7 +549,7 @@ mod tests {
     #[test]
     fn test_agent_directory_from_path_unknown_role() {
         let path = PathBuf::from("/rig/.agents/unknown-role");
-        let agent = AgentDirectory::from_path(path.clone()).unwrap();
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(path.clone()).unwrap()).unwrap();
 
         assert_eq!(agent.role, AgentRole::Unknown);
         assert_eq!(agent.instance_name, "unknown-role");
@@ -254,4 +562,82 @@ mod tests {
         assert_eq!(AgentRole::Polecat.to_string(), "Polecat");
         assert_eq!(AgentRole::Crew.to_string(), "Crew");
     }
+
+    fn agent(path: &str) -> AgentDirectory {
+        AgentDirectory::from_path(AbsPathBuf::try_from(PathBuf::from(path)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_diff_agent_lists_detects_added_and_removed() {
+        let before = vec![agent("/rig/.agents/polecat-1")];
+        let after = vec![agent("/rig/.agents/polecat-2")];
+
+        let events = diff_agent_lists(&before, &after, &[]);
+
+        assert_eq!(
+            events,
+            vec![
+                AgentEvent::Removed(PathBuf::from("/rig/.agents/polecat-1")),
+                AgentEvent::Added(agent("/rig/.agents/polecat-2")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_agent_lists_reports_os_rename_pair_as_renamed() {
+        let before = vec![agent("/rig/.agents/polecat-1")];
+        let after = vec![agent("/rig/.agents/polecat-2")];
+        let renames = vec![(
+            PathBuf::from("/rig/.agents/polecat-1"),
+            PathBuf::from("/rig/.agents/polecat-2"),
+        )];
+
+        let events = diff_agent_lists(&before, &after, &renames);
+
+        assert_eq!(
+            events,
+            vec![AgentEvent::Renamed {
+                from: PathBuf::from("/rig/.agents/polecat-1"),
+                to: agent("/rig/.agents/polecat-2"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_agent_lists_ignores_stale_rename_pair() {
+        // A rename pair whose endpoints don't show up in before/after
+        // (e.g. from a different, now-irrelevant debounce window) is
+        // simply ignored rather than fabricating an event.
+        let before = vec![agent("/rig/.agents/polecat-1")];
+        let after = vec![agent("/rig/.agents/polecat-1")];
+        let renames = vec![(
+            PathBuf::from("/rig/.agents/polecat-9"),
+            PathBuf::from("/rig/.agents/polecat-10"),
+        )];
+
+        assert!(diff_agent_lists(&before, &after, &renames).is_empty());
+    }
+
+    #[test]
+    fn test_diff_agent_lists_unchanged_path_is_quiet() {
+        let before = vec![agent("/rig/.agents/polecat-1")];
+        let after = before.clone();
+
+        assert!(diff_agent_lists(&before, &after, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_dedupe_by_canonical_path_keeps_first_occurrence() {
+        let agents = vec![
+            agent("/rig/.agents/polecat-1"),
+            agent("/rig/.agents/polecat-1"),
+            agent("/rig/.agents/polecat-2"),
+        ];
+
+        let deduped = dedupe_by_canonical_path(agents);
+
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].instance_name, "polecat-1");
+        assert_eq!(deduped[1].instance_name, "polecat-2");
+    }
 }
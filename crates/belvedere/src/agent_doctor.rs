@@ -0,0 +1,269 @@
+//! Per-agent diagnostics: inspects a discovered [`AgentDirectory`] for
+//! common problems (a directory name `scan_agents_directory` couldn't
+//! recognize, a manifest that disagrees with the directory name, a stale
+//! or malformed `Cargo.lock`) and reports them with a severity, the way an
+//! `info`/`doctor` command would.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::agent_discovery::{AgentDirectory, AgentRole};
+use crate::rig_vcs::rig_root_of;
+
+/// How serious a [`Finding`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Ok,
+    Warn,
+    Error,
+}
+
+/// A single diagnostic observation about an agent directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// The full diagnostic result for one agent directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentReport {
+    pub agent: AgentDirectory,
+    pub findings: Vec<Finding>,
+    /// Most recent modification time under `agent.path`, if it could be read.
+    pub last_activity: Option<SystemTime>,
+    /// `name`/`version`/`source` triples read from the rig's `Cargo.lock`,
+    /// if this agent's rig is a Rust project.
+    pub locked_packages: Vec<LockedPackage>,
+}
+
+impl AgentReport {
+    /// The worst severity among this report's findings, or `Ok` if there
+    /// are none.
+    pub fn severity(&self) -> Severity {
+        self.findings
+            .iter()
+            .map(|f| f.severity)
+            .max()
+            .unwrap_or(Severity::Ok)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct CargoLock {
+    #[serde(default, rename = "package")]
+    packages: Vec<LockedPackage>,
+}
+
+/// Declares the fields an agent manifest (`agent.toml`, in the agent's own
+/// directory) may set; used only to cross-check against the directory
+/// name's inferred role.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct AgentManifest {
+    role: Option<AgentRole>,
+}
+
+const REQUIRED_SUBDIRS: &[&str] = &["logs"];
+
+/// Inspects [`AgentDirectory`]s and produces an [`AgentReport`] for each.
+pub struct Doctor;
+
+impl Doctor {
+    /// Inspects a single agent directory.
+    pub fn inspect(agent: &AgentDirectory) -> AgentReport {
+        let mut findings = Vec::new();
+
+        if agent.role == AgentRole::Unknown {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                message: format!(
+                    "directory name `{}` doesn't match a known role prefix; add it to belvedere.toml's role_prefixes to recognize it",
+                    agent.instance_name
+                ),
+            });
+        }
+
+        if let Some(manifest) = read_manifest(agent.path.as_ref()) {
+            if let Some(declared_role) = manifest.role {
+                if declared_role != agent.role {
+                    findings.push(Finding {
+                        severity: Severity::Warn,
+                        message: format!(
+                            "agent.toml declares role {declared_role}, but the directory name implies {}",
+                            agent.role
+                        ),
+                    });
+                }
+            }
+        }
+
+        for subdir in REQUIRED_SUBDIRS {
+            if !agent.path.join(subdir).is_dir() {
+                findings.push(Finding {
+                    severity: Severity::Warn,
+                    message: format!("missing expected `{subdir}/` subdirectory"),
+                });
+            }
+        }
+
+        let last_activity = latest_modification(agent.path.as_ref());
+
+        let rig_root = rig_root_of(agent);
+
+        let locked_packages = rig_root
+            .as_deref()
+            .and_then(read_cargo_lock)
+            .unwrap_or_default();
+
+        if locked_packages.is_empty() {
+            if let Some(rig_root) = rig_root.as_deref() {
+                if rig_root.join("Cargo.toml").is_file() && !rig_root.join("Cargo.lock").is_file() {
+                    findings.push(Finding {
+                        severity: Severity::Warn,
+                        message: "rig has a Cargo.toml but no Cargo.lock".to_string(),
+                    });
+                }
+            }
+        }
+
+        if findings.is_empty() {
+            findings.push(Finding {
+                severity: Severity::Ok,
+                message: "no issues found".to_string(),
+            });
+        }
+
+        AgentReport {
+            agent: agent.clone(),
+            findings,
+            last_activity,
+            locked_packages,
+        }
+    }
+
+    /// Inspects every agent in `agents`.
+    pub fn inspect_all(agents: &[AgentDirectory]) -> Vec<AgentReport> {
+        agents.iter().map(Self::inspect).collect()
+    }
+}
+
+fn read_manifest(agent_dir: &Path) -> Option<AgentManifest> {
+    let contents = std::fs::read_to_string(agent_dir.join("agent.toml")).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Reads `<rig_root>/Cargo.lock`'s locked packages, if the rig is a Rust
+/// project with a lockfile.
+fn read_cargo_lock(rig_root: &Path) -> Option<Vec<LockedPackage>> {
+    let contents = std::fs::read_to_string(rig_root.join("Cargo.lock")).ok()?;
+    let lock: CargoLock = toml::from_str(&contents).ok()?;
+    Some(lock.packages)
+}
+
+/// Walks `dir` one level deep and returns the most recent modification
+/// time seen, including `dir` itself.
+fn latest_modification(dir: &Path) -> Option<SystemTime> {
+    let mut latest = std::fs::metadata(dir).ok()?.modified().ok();
+
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                latest = Some(latest.map_or(modified, |current| current.max(modified)));
+            }
+        }
+    }
+
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsPathBuf;
+
+    /// Builds a `<rig_root>/.agents/<agent_name>` tree under a fresh temp
+    /// directory and returns the `AgentDirectory` for the agent.
+    fn rig_with_agent(test_name: &str, agent_name: &str) -> (std::path::PathBuf, AgentDirectory) {
+        let rig_root = std::env::temp_dir().join(format!(
+            "belvedere-agent-doctor-test-{test_name}-{}",
+            std::process::id()
+        ));
+        let agent_path = rig_root.join(".agents").join(agent_name);
+        std::fs::create_dir_all(&agent_path).unwrap();
+
+        let agent = AgentDirectory::from_path(AbsPathBuf::try_from(agent_path).unwrap()).unwrap();
+        (rig_root, agent)
+    }
+
+    #[test]
+    fn test_inspect_reads_cargo_lock_from_rig_root_not_agents_dir() {
+        let (rig_root, agent) = rig_with_agent("cargo-lock", "polecat-1");
+
+        std::fs::write(
+            rig_root.join("Cargo.lock"),
+            r#"
+[[package]]
+name = "example"
+version = "0.1.0"
+"#,
+        )
+        .unwrap();
+
+        let report = Doctor::inspect(&agent);
+
+        assert_eq!(
+            report.locked_packages,
+            vec![LockedPackage {
+                name: "example".to_string(),
+                version: "0.1.0".to_string(),
+                source: None,
+            }]
+        );
+
+        std::fs::remove_dir_all(&rig_root).ok();
+    }
+
+    #[test]
+    fn test_inspect_warns_on_cargo_toml_without_lock_at_rig_root() {
+        let (rig_root, agent) = rig_with_agent("cargo-toml-no-lock", "polecat-1");
+
+        std::fs::write(rig_root.join("Cargo.toml"), "[package]\nname = \"example\"\n").unwrap();
+
+        let report = Doctor::inspect(&agent);
+
+        assert!(report.locked_packages.is_empty());
+        assert!(
+            report
+                .findings
+                .iter()
+                .any(|f| f.message.contains("Cargo.toml but no Cargo.lock"))
+        );
+
+        std::fs::remove_dir_all(&rig_root).ok();
+    }
+
+    #[test]
+    fn test_inspect_is_quiet_when_rig_has_no_cargo_project() {
+        let (rig_root, agent) = rig_with_agent("no-cargo", "polecat-1");
+
+        let report = Doctor::inspect(&agent);
+
+        assert!(report.locked_packages.is_empty());
+        assert!(
+            !report
+                .findings
+                .iter()
+                .any(|f| f.message.contains("Cargo.lock"))
+        );
+
+        std::fs::remove_dir_all(&rig_root).ok();
+    }
+}
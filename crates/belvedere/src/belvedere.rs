@@ -1,14 +1,25 @@
+mod abs_path;
+mod agent_doctor;
 mod agent_section;
+mod config;
 mod convoy_section;
 mod dashboard_buffer;
 mod rig_section;
+mod rig_vcs;
 pub mod agent_discovery;
+pub mod dock;
+pub mod open;
 pub mod town;
 pub mod town_item;
 
 #[cfg(test)]
 mod dashboard_buffer_tests;
 
+pub use abs_path::AbsPathBuf;
 pub use agent_discovery::{AgentDirectory, AgentDiscovery, AgentRole};
+pub use agent_doctor::{AgentReport, Doctor, Finding, Severity};
+pub use rig_vcs::{RigContext, RigRepository, RigVcs, group_by_rig};
+pub use dock::{Dock, DockPosition, Panel};
+pub use open::{open_directory, reveal_in_file_manager};
 pub use town::Town;
-pub use town_item::{TownItem, TownItemEvent, TabContentParams};
+pub use town_item::{TabContentParams, TownItem, TownItemEvent, TownItemHandle};
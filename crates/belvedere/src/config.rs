@@ -0,0 +1,88 @@
+//! Declarative discovery config loaded from a `belvedere.toml`, for teams
+//! whose agent/rig layout doesn't match the hardcoded `~/.gazetown/agents`
+//! and `<rig>/.agents` conventions.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::agent_discovery::AgentRole;
+
+const CONFIG_FILE_NAME: &str = "belvedere.toml";
+
+/// Discovery roots and role-prefix overrides read from `belvedere.toml`.
+/// Every pattern is a glob evaluated relative to the directory the config
+/// file was found in, the same way Cargo workspace `members` globs work.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct DiscoveryConfig {
+    /// Glob patterns matching agent directories directly, e.g.
+    /// `"agents/*"` or `"rigs/*/.agents/*"`.
+    #[serde(default)]
+    pub agents: Vec<String>,
+    /// Glob patterns matching rig roots to scan for a `.agents` folder.
+    #[serde(default)]
+    pub rig_roots: Vec<String>,
+    /// Extra directory-name prefixes mapped to an [`AgentRole`], layered
+    /// on top of the built-in `mayor`/`polecat`/`crew`/`witness`/`deacon`
+    /// prefixes.
+    #[serde(default)]
+    pub role_prefixes: HashMap<String, AgentRole>,
+}
+
+impl DiscoveryConfig {
+    /// Searches for `belvedere.toml` starting at `start` and walking up
+    /// through its ancestors as far as `$HOME` (inclusive), returning the
+    /// parsed config and the directory it was found in. Patterns are
+    /// resolved relative to that directory.
+    pub fn find(start: &Path) -> Option<(PathBuf, Self)> {
+        let home = dirs::home_dir();
+
+        for dir in start.ancestors() {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                let contents = std::fs::read_to_string(&candidate).ok()?;
+                let config = toml::from_str(&contents).ok()?;
+                return Some((dir.to_path_buf(), config));
+            }
+
+            if home.as_deref() == Some(dir) {
+                break;
+            }
+        }
+
+        None
+    }
+
+    /// Expands `self.agents` against `base`, deduplicating matches.
+    pub fn matched_agent_dirs(&self, base: &Path) -> Vec<PathBuf> {
+        expand_globs(base, &self.agents)
+    }
+
+    /// Expands `self.rig_roots` against `base`, deduplicating matches.
+    pub fn matched_rig_roots(&self, base: &Path) -> Vec<PathBuf> {
+        expand_globs(base, &self.rig_roots)
+    }
+}
+
+/// Expands each glob pattern in `patterns` relative to `base`, returning
+/// the matched paths in order with duplicates removed.
+fn expand_globs(base: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut seen = std::collections::HashSet::new();
+    let mut matched = Vec::new();
+
+    for pattern in patterns {
+        let Some(full_pattern) = base.join(pattern).to_str().map(str::to_string) else {
+            continue;
+        };
+        let Ok(paths) = glob::glob(&full_pattern) else {
+            continue;
+        };
+
+        for path in paths.flatten() {
+            if seen.insert(path.clone()) {
+                matched.push(path);
+            }
+        }
+    }
+
+    matched
+}
@@ -0,0 +1,152 @@
+use gpui::{AnyElement, AnyView, App, Window};
+
+/// Which edge of the window a [`Dock`] is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DockPosition {
+    Left,
+    Right,
+    Bottom,
+}
+
+/// A panel that can be hosted in a [`Dock`]: the convoy list, an agent
+/// browser, a rig browser, and so on.
+pub trait Panel {
+    /// Icon shown in the dock's panel strip.
+    fn icon(&self, cx: &App) -> gpui::Icon;
+
+    /// Label shown in the panel strip's tooltip.
+    fn label(&self, cx: &App) -> gpui::SharedString;
+
+    /// Which dock this panel belongs in by default.
+    fn position(&self, cx: &App) -> DockPosition;
+
+    /// Default pixel size (width for `Left`/`Right`, height for `Bottom`).
+    fn default_size(&self, cx: &App) -> gpui::Pixels;
+
+    /// Renders the panel body.
+    fn render(&mut self, window: &mut Window, cx: &mut App) -> AnyElement;
+}
+
+/// One registered panel entity plus the metadata `Dock` needs to render its
+/// strip entry without downcasting the view.
+struct RegisteredPanel {
+    view: AnyView,
+    icon: gpui::Icon,
+    label: gpui::SharedString,
+}
+
+/// Holds the panels registered for a single edge of the window: a strip of
+/// icons plus the body of whichever panel is active, collapsing to zero
+/// width/height when closed. Mirrors Zed's `dock::Dock`.
+pub struct Dock {
+    position: DockPosition,
+    panels: Vec<RegisteredPanel>,
+    active_panel_index: Option<usize>,
+    is_open: bool,
+    /// Persisted pixel size of the dock (width for `Left`/`Right`, height for `Bottom`).
+    size: gpui::Pixels,
+}
+
+impl Dock {
+    pub fn new(position: DockPosition, default_size: gpui::Pixels) -> Self {
+        Self {
+            position,
+            panels: Vec::new(),
+            active_panel_index: None,
+            is_open: false,
+            size: default_size,
+        }
+    }
+
+    pub fn position(&self) -> DockPosition {
+        self.position
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open && !self.panels.is_empty()
+    }
+
+    pub fn size(&self) -> gpui::Pixels {
+        self.size
+    }
+
+    pub fn resize(&mut self, size: gpui::Pixels) {
+        self.size = size;
+    }
+
+    pub fn toggle_open(&mut self) {
+        self.is_open = !self.is_open;
+    }
+
+    /// Registers a panel view in this dock. The first panel registered
+    /// becomes the active one.
+    pub fn add_panel(&mut self, view: AnyView, icon: gpui::Icon, label: impl Into<gpui::SharedString>) {
+        self.panels.push(RegisteredPanel {
+            view,
+            icon,
+            label: label.into(),
+        });
+        if self.active_panel_index.is_none() {
+            self.active_panel_index = Some(0);
+        }
+    }
+
+    pub fn activate_panel(&mut self, index: usize) {
+        if index < self.panels.len() {
+            self.active_panel_index = Some(index);
+            self.is_open = true;
+        }
+    }
+
+    fn active_panel(&self) -> Option<&RegisteredPanel> {
+        self.active_panel_index.and_then(|i| self.panels.get(i))
+    }
+
+    /// Renders the dock's vertical strip of panel icons plus the active
+    /// panel's body, or nothing when the dock is closed or empty.
+    pub fn render(&self, colors: &ui::ThemeColors) -> gpui::AnyElement {
+        use gpui::prelude::*;
+        use gpui::div;
+
+        if !self.is_open() {
+            return div().id(self.strip_id()).into_any_element();
+        }
+
+        let mut container = div()
+            .id(self.strip_id())
+            .flex()
+            .h_full()
+            .bg(colors.panel_background);
+
+        container = match self.position {
+            DockPosition::Bottom => container.flex_col().w_full().h(self.size),
+            _ => container.flex_row().h_full().w(self.size),
+        };
+
+        let strip = div()
+            .id("panel-strip")
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_1()
+            .children(self.panels.iter().enumerate().map(|(index, panel)| {
+                div()
+                    .id(("panel-icon", index))
+                    .tooltip_text(panel.label.clone())
+                    .child(panel.icon.clone())
+            }));
+
+        container
+            .child(strip)
+            .children(self.active_panel().map(|panel| panel.view.clone()))
+            .into_any_element()
+    }
+
+    fn strip_id(&self) -> &'static str {
+        match self.position {
+            DockPosition::Left => "left-dock",
+            DockPosition::Right => "right-dock",
+            DockPosition::Bottom => "bottom-dock",
+        }
+    }
+}
@@ -3,8 +3,9 @@
 
 use anyhow::Result;
 use assets::Assets;
-use belvedere::Town;
+use belvedere::{AgentDiscovery, Doctor, Town, group_by_rig};
 use gpui::{App, AppContext, Application, Bounds, WindowBounds, WindowOptions, actions, px, size};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 actions!(belvedere, [Quit]);
@@ -42,7 +43,18 @@ fn main() -> Result<()> {
                 }),
                 ..Default::default()
             },
-            |_, cx| cx.new(|cx| Town::new(gt_path, cx)),
+            |_, cx| {
+                cx.new(|cx| {
+                    let mut town = Town::new(gt_path.clone(), cx);
+                    let agents = AgentDiscovery::new(Some(gt_path.clone())).discover_agents();
+                    town.set_doctor_reports(Doctor::inspect_all(&agents), cx);
+
+                    let mut repos = HashMap::new();
+                    town.set_rig_contexts(group_by_rig(agents, &mut repos), cx);
+
+                    town
+                })
+            },
         )
         .expect("Failed to open window");
     });
@@ -0,0 +1,196 @@
+//! Launching the user's editor/file manager on a discovered agent's
+//! directory. The tricky part is environment hygiene: Belvedere ships as a
+//! bundled GPUI binary, so path-list env vars like `LD_LIBRARY_PATH` and
+//! `PATH` point at bundle-local directories that would break an external
+//! app if inherited verbatim.
+
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use crate::agent_discovery::AgentDirectory;
+
+/// Path-list environment variables that may carry bundle-local entries a
+/// spawned external app shouldn't inherit.
+const PATH_LIST_VARS: &[&str] = &["PATH", "LD_LIBRARY_PATH", "GST_PLUGIN_PATH", "GST_PLUGIN_SYSTEM_PATH", "XDG_DATA_DIRS"];
+
+/// Builds the environment a spawned external process (editor, file
+/// manager) should see: each path-list variable in [`PATH_LIST_VARS`] has
+/// its bundle-local entries stripped and empty entries dropped, keeping
+/// only system entries (in original order, deduplicated), and a variable
+/// that ends up empty is omitted entirely rather than set to `""`.
+fn sanitized_spawn_env(bundle_dir: &Path) -> Vec<(String, String)> {
+    let mut overrides = Vec::new();
+
+    for var in PATH_LIST_VARS {
+        let Ok(value) = env::var(var) else {
+            continue;
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        let mut system_entries = Vec::new();
+
+        for entry in value.split(':') {
+            if entry.is_empty() {
+                continue;
+            }
+            if Path::new(entry).starts_with(bundle_dir) {
+                continue;
+            }
+            if seen.insert(entry) {
+                system_entries.push(entry);
+            }
+        }
+
+        if !system_entries.is_empty() {
+            overrides.push((var.to_string(), system_entries.join(":")));
+        }
+    }
+
+    overrides
+}
+
+/// Variables that, if present and non-empty, indicate Belvedere is running
+/// inside a sandbox that isolates it from the host's default-application
+/// mechanism.
+fn running_sandboxed() -> bool {
+    ["FLATPAK_ID", "SNAP", "APPIMAGE", "APPDIR"]
+        .iter()
+        .any(|var| env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Wraps `command` so it runs on the host when Belvedere is running inside
+/// a Flatpak/Snap/AppImage sandbox, via `flatpak-spawn --host`.
+fn host_command(program: &str, args: &[&str]) -> Command {
+    if running_sandboxed() {
+        let mut command = Command::new("flatpak-spawn");
+        command.arg("--host").arg(program).args(args);
+        command
+    } else {
+        let mut command = Command::new(program);
+        command.args(args);
+        command
+    }
+}
+
+fn spawn_with_sanitized_env(mut command: Command) -> io::Result<()> {
+    if let Ok(exe) = env::current_exe() {
+        if let Some(bundle_dir) = exe.parent() {
+            command.env_clear_path_list_vars();
+            for (var, value) in sanitized_spawn_env(bundle_dir) {
+                command.env(var, value);
+            }
+        }
+    }
+
+    command.spawn()?;
+    Ok(())
+}
+
+/// Small extension trait so [`spawn_with_sanitized_env`] can unset the
+/// path-list vars it's about to override (or drop, if they'd end up
+/// empty) without clearing the rest of the inherited environment.
+trait ClearPathListVars {
+    fn env_clear_path_list_vars(&mut self) -> &mut Self;
+}
+
+impl ClearPathListVars for Command {
+    fn env_clear_path_list_vars(&mut self) -> &mut Self {
+        for var in PATH_LIST_VARS {
+            self.env_remove(var);
+        }
+        self
+    }
+}
+
+/// Opens `dir`'s path in the user's default editor/file manager.
+pub fn open_directory(dir: &AgentDirectory) -> io::Result<()> {
+    let path = dir.path.to_string_lossy().into_owned();
+
+    let command = if cfg!(target_os = "macos") {
+        host_command("open", &[&path])
+    } else if cfg!(target_os = "windows") {
+        host_command("explorer", &[&path])
+    } else {
+        host_command("xdg-open", &[&path])
+    };
+
+    spawn_with_sanitized_env(command)
+}
+
+/// Reveals `dir`'s path within its parent, rather than opening its
+/// contents.
+pub fn reveal_in_file_manager(dir: &AgentDirectory) -> io::Result<()> {
+    let path = dir.path.to_string_lossy().into_owned();
+
+    let command = if cfg!(target_os = "macos") {
+        host_command("open", &["-R", &path])
+    } else if cfg!(target_os = "windows") {
+        host_command("explorer", &[&format!("/select,{path}")])
+    } else {
+        // No universal freedesktop "select in file manager" verb exists;
+        // fall back to opening the parent directory.
+        let parent = dir
+            .path
+            .parent()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or(path);
+        host_command("xdg-open", &[&parent])
+    };
+
+    spawn_with_sanitized_env(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitized_spawn_env_drops_bundle_entries() {
+        let bundle_dir = Path::new("/opt/belvedere/lib");
+        unsafe {
+            env::set_var(
+                "LD_LIBRARY_PATH",
+                "/opt/belvedere/lib:/usr/lib:/opt/belvedere/lib",
+            );
+        }
+
+        let overrides = sanitized_spawn_env(bundle_dir);
+        let ld_library_path = overrides
+            .iter()
+            .find(|(var, _)| var == "LD_LIBRARY_PATH")
+            .map(|(_, value)| value.clone());
+
+        assert_eq!(ld_library_path, Some("/usr/lib".to_string()));
+
+        unsafe {
+            env::remove_var("LD_LIBRARY_PATH");
+        }
+    }
+
+    #[test]
+    fn test_sanitized_spawn_env_omits_empty_results() {
+        unsafe {
+            env::set_var("GST_PLUGIN_PATH", "");
+        }
+
+        let overrides = sanitized_spawn_env(Path::new("/opt/belvedere"));
+        assert!(!overrides.iter().any(|(var, _)| var == "GST_PLUGIN_PATH"));
+
+        unsafe {
+            env::remove_var("GST_PLUGIN_PATH");
+        }
+    }
+
+    #[test]
+    fn test_running_sandboxed_detects_flatpak() {
+        unsafe {
+            env::set_var("FLATPAK_ID", "org.example.Belvedere");
+        }
+        assert!(running_sandboxed());
+        unsafe {
+            env::remove_var("FLATPAK_ID");
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Git metadata for rig roots: which branch a rig's agents are operating
+//! on, and whether the rig is mid-rebase/merge, so Belvedere can show that
+//! context alongside each Polecat/Witness instead of treating every rig
+//! directory the same regardless of VCS state.
+
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use git2::{Repository, RepositoryState};
+
+use crate::agent_discovery::AgentDirectory;
+
+/// A rig root's git state at the time it was last inspected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RigVcs {
+    /// Current branch name, or `None` for a detached `HEAD` or an unborn
+    /// branch (a repository with no commits yet).
+    pub branch: Option<String>,
+    /// Clean, mid-merge, mid-rebase, etc.
+    pub state: RepositoryState,
+    /// Whether `rig_root` is a linked worktree rather than the repository's
+    /// primary checkout.
+    pub is_worktree: bool,
+    /// Whether `HEAD` points directly at a commit rather than a branch.
+    pub detached: bool,
+}
+
+impl RigVcs {
+    /// Reads `repo`'s current state. An unborn branch (no commits yet)
+    /// or a detached `HEAD` is represented in the returned value rather
+    /// than surfaced as an error.
+    fn inspect(repo: &Repository) -> Self {
+        let detached = repo.head_detached().unwrap_or(false);
+
+        // `head()` errors with `UnbornBranch` for a repository with no
+        // commits yet; a detached `HEAD` resolves to a non-branch reference.
+        let branch = repo
+            .head()
+            .ok()
+            .filter(|head| head.is_branch())
+            .and_then(|head| head.shorthand().map(str::to_string));
+
+        RigVcs {
+            branch,
+            state: repo.state(),
+            is_worktree: repo.is_worktree(),
+            detached,
+        }
+    }
+}
+
+/// Lazily opens and caches a single rig root's git repository handle, so
+/// every agent discovered under the same rig shares one open repository
+/// instead of each re-opening it.
+pub struct RigRepository {
+    root: PathBuf,
+    repo: OnceCell<Option<Repository>>,
+}
+
+impl RigRepository {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            repo: OnceCell::new(),
+        }
+    }
+
+    fn open(&self) -> Option<&Repository> {
+        self.repo
+            .get_or_init(|| Repository::open(&self.root).ok())
+            .as_ref()
+    }
+
+    /// This rig root's git state, or `None` if it isn't a git repository.
+    pub fn vcs(&self) -> Option<RigVcs> {
+        self.open().map(RigVcs::inspect)
+    }
+}
+
+/// Agents grouped by the rig root that owns them, alongside that rig's
+/// VCS state.
+pub struct RigContext {
+    pub rig_root: PathBuf,
+    pub vcs: Option<RigVcs>,
+    pub agents: Vec<AgentDirectory>,
+}
+
+/// Groups `agents` by their owning rig root (the parent of the `.agents`
+/// directory they were discovered in), opening each distinct root's git
+/// repository at most once via `repos`.
+pub fn group_by_rig(
+    agents: Vec<AgentDirectory>,
+    repos: &mut HashMap<PathBuf, RigRepository>,
+) -> Vec<RigContext> {
+    let mut order: Vec<PathBuf> = Vec::new();
+    let mut grouped: HashMap<PathBuf, Vec<AgentDirectory>> = HashMap::new();
+
+    for agent in agents {
+        let Some(rig_root) = rig_root_of(&agent) else {
+            continue;
+        };
+
+        grouped.entry(rig_root.clone()).or_insert_with(|| {
+            order.push(rig_root.clone());
+            Vec::new()
+        });
+        grouped.get_mut(&rig_root).unwrap().push(agent);
+    }
+
+    order
+        .into_iter()
+        .map(|rig_root| {
+            let agents = grouped.remove(&rig_root).unwrap_or_default();
+            let vcs = repos
+                .entry(rig_root.clone())
+                .or_insert_with(|| RigRepository::new(rig_root.clone()))
+                .vcs();
+
+            RigContext {
+                rig_root,
+                vcs,
+                agents,
+            }
+        })
+        .collect()
+}
+
+/// The rig root an agent was discovered under: the grandparent of its
+/// path (`<rig_root>/.agents/<agent>`).
+pub(crate) fn rig_root_of(agent: &AgentDirectory) -> Option<PathBuf> {
+    agent
+        .path
+        .parent()
+        .and_then(Path::parent)
+        .map(Path::to_path_buf)
+}
@@ -0,0 +1,1391 @@
+use collections::HashMap;
+use gpui::{
+    AnyView, App, Context, FocusHandle, Focusable, MouseButton, PromptLevel, Render, SharedString,
+    Task, Timer, Window, div, prelude::*,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+use ui::ActiveTheme;
+
+use crate::dock::{Dock, DockPosition};
+use crate::town_item::{TabContentParams, TownItemEvent, TownItemHandle};
+
+/// Payload carried by a tab being dragged: where it's being dragged from,
+/// mirroring Zed's `dragged_item_receiver` pattern.
+#[derive(Clone)]
+struct DraggedTab {
+    pane_path: PanePath,
+    item_index: usize,
+}
+
+/// Drag-and-drop preview rendered under the cursor while a tab is dragged.
+struct DraggedTabPreview {
+    label: SharedString,
+}
+
+impl Render for DraggedTabPreview {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(gpui::black().opacity(0.8))
+            .text_color(gpui::white())
+            .child(self.label.clone())
+    }
+}
+
+/// Maximum number of entries retained in a pane's back/forward navigation
+/// history, bounding memory for panes left open a long time.
+const NAVIGATION_HISTORY_CAP: usize = 64;
+
+/// Governs when a dirty `TownItem` is saved automatically, mirroring Zed's
+/// pane autosave setting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Autosave {
+    /// Never save automatically; dirty items are only saved when the user
+    /// explicitly does so, or accepts a [`Town::prompt_to_close`] prompt.
+    Off,
+    /// Save `millis` after the item was last edited, debounced so that
+    /// further edits push the save back out by the full delay again.
+    AfterDelay { millis: u64 },
+    /// Save when [`Town::set_active_item`] switches the pane's active tab
+    /// away from the item. Other activation changes (opening an item,
+    /// back/forward navigation, moving tabs) don't trigger a save.
+    OnFocusChange,
+    /// Selectable, but `Town` has no window-focus trigger: no automatic
+    /// save happens under this setting.
+    OnWindowChange,
+}
+
+/// Horizontal or vertical split orientation for a [`PaneGroup`] node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// Holds the tabbed items in a single leaf pane of the center area.
+pub struct CenterPane {
+    /// List of open items
+    items: Vec<Box<dyn TownItemHandle>>,
+    /// Index of the currently active item
+    active_index: usize,
+    /// Previously active indices, most recent last; `go_back` pops from here.
+    back_history: VecDeque<usize>,
+    /// Indices popped by `go_back`, most recent last; `go_forward` pops from here.
+    forward_history: VecDeque<usize>,
+    /// Index of the tab whose right-click context menu is currently open, if any.
+    context_menu_index: Option<usize>,
+}
+
+impl CenterPane {
+    fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            active_index: 0,
+            back_history: VecDeque::new(),
+            forward_history: VecDeque::new(),
+            context_menu_index: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn active_item(&self) -> Option<&dyn TownItemHandle> {
+        self.items.get(self.active_index).map(|item| item.as_ref())
+    }
+
+    fn item_at(&self, index: usize) -> Option<&dyn TownItemHandle> {
+        self.items.get(index).map(|item| item.as_ref())
+    }
+
+    fn add_item(&mut self, item: Box<dyn TownItemHandle>) {
+        self.record_activation();
+        self.items.push(item);
+        self.active_index = self.items.len().saturating_sub(1);
+    }
+
+    fn close_item(&mut self, index: usize, cx: &App) -> Option<AnyView> {
+        if index >= self.items.len() || !self.items[index].can_close(cx) {
+            return None;
+        }
+        let removed = self.items.remove(index);
+        if self.active_index >= self.items.len() && !self.items.is_empty() {
+            self.active_index = self.items.len() - 1;
+        }
+        Self::remap_history(&mut self.back_history, index);
+        Self::remap_history(&mut self.forward_history, index);
+        removed.on_removed(cx);
+        Some(removed.any_view())
+    }
+
+    /// Closes every item to the left of `index` whose `TownItem::can_close`
+    /// allows it, returning the views that were actually removed.
+    fn close_items_to_the_left(&mut self, index: usize, cx: &App) -> Vec<AnyView> {
+        self.close_where(cx, |i| i < index)
+    }
+
+    /// Closes every item to the right of `index` whose `TownItem::can_close`
+    /// allows it, returning the views that were actually removed.
+    fn close_items_to_the_right(&mut self, index: usize, cx: &App) -> Vec<AnyView> {
+        self.close_where(cx, |i| i > index)
+    }
+
+    /// Closes every item other than `index` whose `TownItem::can_close`
+    /// allows it, returning the views that were actually removed.
+    fn close_other_items(&mut self, index: usize, cx: &App) -> Vec<AnyView> {
+        self.close_where(cx, |i| i != index)
+    }
+
+    /// Closes every closable item in the pane, returning the views that were
+    /// actually removed.
+    fn close_all_items(&mut self, cx: &App) -> Vec<AnyView> {
+        self.close_where(cx, |_| true)
+    }
+
+    /// Removes every item at an index matching `predicate` and allowed to
+    /// close, re-deriving `active_index` to the nearest surviving tab (by
+    /// tracking how the active tab's position shifts as earlier tabs are
+    /// removed) and remapping the navigation history stacks accordingly.
+    fn close_where(&mut self, cx: &App, predicate: impl Fn(usize) -> bool) -> Vec<AnyView> {
+        let mut surviving_active = self.active_index;
+        let mut removed = Vec::new();
+        let mut index = 0;
+        while index < self.items.len() {
+            if predicate(index) && self.items[index].can_close(cx) {
+                if index < surviving_active {
+                    surviving_active -= 1;
+                }
+                let item = self.items.remove(index);
+                item.on_removed(cx);
+                removed.push(item.any_view());
+                Self::remap_history(&mut self.back_history, index);
+                Self::remap_history(&mut self.forward_history, index);
+                continue;
+            }
+            index += 1;
+        }
+
+        self.active_index = if self.items.is_empty() {
+            0
+        } else {
+            surviving_active.min(self.items.len() - 1)
+        };
+
+        removed
+    }
+
+    /// Removes and returns the item at `index` unconditionally, for moving it
+    /// to another pane. Unlike [`Self::close_item`], this doesn't consult
+    /// `TownItem::can_close` since the item isn't being closed.
+    fn take_item(&mut self, index: usize) -> Option<Box<dyn TownItemHandle>> {
+        if index >= self.items.len() {
+            return None;
+        }
+        let item = self.items.remove(index);
+        if self.active_index >= self.items.len() && !self.items.is_empty() {
+            self.active_index = self.items.len() - 1;
+        }
+        Self::remap_history(&mut self.back_history, index);
+        Self::remap_history(&mut self.forward_history, index);
+        Some(item)
+    }
+
+    /// Inserts `item` at `index` (clamped to the current length) and
+    /// activates it.
+    fn insert_item_at(&mut self, index: usize, item: Box<dyn TownItemHandle>) {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        self.active_index = index;
+    }
+
+    /// Activates the item at `index`, returning the index that was
+    /// previously active so callers can act on it (e.g. autosave-on-focus-change).
+    fn set_active(&mut self, index: usize) -> Option<usize> {
+        if index < self.items.len() && index != self.active_index {
+            let previous = self.active_index;
+            self.record_activation();
+            self.active_index = index;
+            Some(previous)
+        } else {
+            None
+        }
+    }
+
+    /// Pushes the currently active index onto the back stack and clears the
+    /// forward stack, as any normal (non-back/forward) activation should.
+    fn record_activation(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.back_history.push_back(self.active_index);
+        if self.back_history.len() > NAVIGATION_HISTORY_CAP {
+            self.back_history.pop_front();
+        }
+        self.forward_history.clear();
+    }
+
+    fn can_go_back(&self) -> bool {
+        !self.back_history.is_empty()
+    }
+
+    fn can_go_forward(&self) -> bool {
+        !self.forward_history.is_empty()
+    }
+
+    fn go_back(&mut self) {
+        while let Some(index) = self.back_history.pop_back() {
+            if index >= self.items.len() {
+                // Stale entry left behind by a since-removed tab; skip it.
+                continue;
+            }
+            self.forward_history.push_back(self.active_index);
+            self.active_index = index;
+            return;
+        }
+    }
+
+    fn go_forward(&mut self) {
+        while let Some(index) = self.forward_history.pop_back() {
+            if index >= self.items.len() {
+                continue;
+            }
+            self.back_history.push_back(self.active_index);
+            self.active_index = index;
+            return;
+        }
+    }
+
+    /// Removes stale references to `removed_index` from a history stack and
+    /// shifts references to later indices down by one, keeping the stack
+    /// valid after `close_item` removes a tab.
+    fn remap_history(history: &mut VecDeque<usize>, removed_index: usize) {
+        history.retain_mut(|index| {
+            if *index == removed_index {
+                return false;
+            }
+            if *index > removed_index {
+                *index -= 1;
+            }
+            true
+        });
+    }
+}
+
+/// A node in the recursive layout tree for the center area: either a leaf
+/// pane holding tabs, or a split holding child nodes laid out proportionally
+/// along `direction`, mirroring Zed's `pane_group::PaneGroup`.
+pub enum PaneGroup {
+    Pane(CenterPane),
+    Split {
+        direction: SplitDirection,
+        children: Vec<PaneGroup>,
+        /// Proportional size (0.0..=1.0) of each child, same length as `children`.
+        sizes: Vec<f32>,
+    },
+}
+
+/// Identifies a leaf pane within the [`PaneGroup`] tree by the sequence of
+/// child indices taken from the root to reach it.
+pub type PanePath = Vec<usize>;
+
+/// Adjusts `path` for the sibling-index shift caused by removing `removed`
+/// from its parent's `children` (as [`PaneGroup::close_at`] does via
+/// `Vec::remove`).
+///
+/// `Vec::remove` shifts every later element down by one index, so if
+/// `removed` and `path` are siblings (same parent, `path`'s last index comes
+/// after `removed`'s), `path` must be decremented at that depth to still
+/// resolve to the same pane post-removal. Any other relationship between the
+/// two paths is left untouched.
+fn remap_after_sibling_removal(removed: &[usize], path: &[usize]) -> PanePath {
+    if removed.len() == path.len() && !path.is_empty() {
+        let depth = path.len() - 1;
+        if removed[..depth] == path[..depth] && path[depth] > removed[depth] {
+            let mut adjusted = path.to_vec();
+            adjusted[depth] -= 1;
+            return adjusted;
+        }
+    }
+    path.to_vec()
+}
+
+impl PaneGroup {
+    fn leaf_at(&self, path: &[usize]) -> Option<&CenterPane> {
+        match (self, path) {
+            (PaneGroup::Pane(pane), []) => Some(pane),
+            (PaneGroup::Split { children, .. }, [first, rest @ ..]) => {
+                children.get(*first)?.leaf_at(rest)
+            }
+            _ => None,
+        }
+    }
+
+    fn leaf_at_mut(&mut self, path: &[usize]) -> Option<&mut CenterPane> {
+        match (self, path) {
+            (PaneGroup::Pane(pane), []) => Some(pane),
+            (PaneGroup::Split { children, .. }, [first, rest @ ..]) => {
+                children.get_mut(*first)?.leaf_at_mut(rest)
+            }
+            _ => None,
+        }
+    }
+
+    /// Splits the leaf pane at `path` in `direction`, moving the existing
+    /// pane and a new empty one under a `Split` node. Reuses the parent split
+    /// if it already runs in the same direction instead of nesting needlessly.
+    fn split_at(&mut self, path: &[usize], direction: SplitDirection) -> Option<PanePath> {
+        match path {
+            [] => {
+                let existing = std::mem::replace(self, PaneGroup::Pane(CenterPane::new()));
+                match existing {
+                    PaneGroup::Pane(pane) => {
+                        *self = PaneGroup::Split {
+                            direction,
+                            children: vec![PaneGroup::Pane(pane), PaneGroup::Pane(CenterPane::new())],
+                            sizes: vec![0.5, 0.5],
+                        };
+                        Some(vec![1])
+                    }
+                    split @ PaneGroup::Split { .. } => {
+                        *self = split;
+                        None
+                    }
+                }
+            }
+            [first, rest @ ..] => {
+                if let PaneGroup::Split {
+                    direction: existing_direction,
+                    children,
+                    sizes,
+                } = self
+                {
+                    if rest.is_empty() && *existing_direction == direction {
+                        children.push(PaneGroup::Pane(CenterPane::new()));
+                        let even_share = 1.0 / children.len() as f32;
+                        sizes.iter_mut().for_each(|s| *s = even_share);
+                        sizes.push(even_share);
+                        return Some(vec![children.len() - 1]);
+                    }
+
+                    let child = children.get_mut(*first)?;
+                    let mut sub_path = child.split_at(rest, direction)?;
+                    sub_path.insert(0, *first);
+                    return Some(sub_path);
+                }
+                None
+            }
+        }
+    }
+
+    /// Removes the empty leaf at `path`, then collapses any ancestor `Split`
+    /// that drops to a single remaining child back into a bare `Pane`.
+    fn close_at(&mut self, path: &[usize]) {
+        let PaneGroup::Split {
+            children, sizes, ..
+        } = self
+        else {
+            return;
+        };
+
+        match path {
+            [only] => {
+                if children
+                    .get(*only)
+                    .is_some_and(|child| matches!(child, PaneGroup::Pane(p) if p.is_empty()))
+                {
+                    children.remove(*only);
+                    sizes.remove(*only);
+                }
+            }
+            [first, rest @ ..] => {
+                if let Some(child) = children.get_mut(*first) {
+                    child.close_at(rest);
+                }
+            }
+            [] => {}
+        }
+
+        if children.len() == 1 {
+            *self = children.remove(0);
+        }
+    }
+
+    fn render(
+        &self,
+        path: &PanePath,
+        colors: &ui::ThemeColors,
+        window: &mut Window,
+        cx: &mut Context<Town>,
+    ) -> gpui::AnyElement {
+        match self {
+            PaneGroup::Pane(pane) => Self::render_pane(pane, path, colors, window, cx),
+            PaneGroup::Split {
+                direction,
+                children,
+                sizes,
+            } => {
+                let mut container = div().flex().flex_1().size_full();
+                container = match direction {
+                    SplitDirection::Horizontal => container.flex_row(),
+                    SplitDirection::Vertical => container.flex_col(),
+                };
+                container
+                    .children(children.iter().zip(sizes).enumerate().map(
+                        |(child_index, (child, size))| {
+                            let mut child_path = path.clone();
+                            child_path.push(child_index);
+                            div()
+                                .flex_grow()
+                                .flex_basis(gpui::relative(*size))
+                                .child(child.render(&child_path, colors, window, cx))
+                        },
+                    ))
+                    .into_any_element()
+            }
+        }
+    }
+
+    fn render_pane(
+        pane: &CenterPane,
+        path: &PanePath,
+        colors: &ui::ThemeColors,
+        window: &mut Window,
+        cx: &mut Context<Town>,
+    ) -> gpui::AnyElement {
+        div()
+            .id("center-pane")
+            .relative()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .h_full()
+            .bg(colors.editor_background)
+            .when(!pane.is_empty(), |div| {
+                div.child(Self::render_tabs(pane, path, colors, window, cx))
+                    .when_some(pane.active_item(), |div, item| {
+                        div.child(item.any_view())
+                    })
+            })
+            .when(pane.is_empty(), |div| {
+                div.items_center().justify_center().child("No items open")
+            })
+            .child(Self::render_split_drop_zone(
+                path,
+                SplitDirection::Horizontal,
+                true,
+                cx,
+            ))
+            .child(Self::render_split_drop_zone(
+                path,
+                SplitDirection::Horizontal,
+                false,
+                cx,
+            ))
+            .child(Self::render_split_drop_zone(
+                path,
+                SplitDirection::Vertical,
+                true,
+                cx,
+            ))
+            .child(Self::render_split_drop_zone(
+                path,
+                SplitDirection::Vertical,
+                false,
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    /// Renders a thin strip along one edge of the pane at `path` that, when
+    /// a dragged tab is dropped on it, splits the pane in `direction` with
+    /// the dragged item as the new pane's sole tab. `leading` picks which
+    /// edge: top/left when true, bottom/right when false.
+    fn render_split_drop_zone(
+        path: &PanePath,
+        direction: SplitDirection,
+        leading: bool,
+        cx: &mut Context<Town>,
+    ) -> gpui::AnyElement {
+        let zone_path = path.clone();
+        let mut zone = div().id(("split-zone", leading as usize)).absolute();
+        zone = match (direction, leading) {
+            (SplitDirection::Horizontal, true) => zone.left_0().top_0().bottom_0().w_8(),
+            (SplitDirection::Horizontal, false) => zone.right_0().top_0().bottom_0().w_8(),
+            (SplitDirection::Vertical, true) => zone.top_0().left_0().right_0().h_8(),
+            (SplitDirection::Vertical, false) => zone.bottom_0().left_0().right_0().h_8(),
+        };
+        zone.drag_over::<DraggedTab>(|style, _drag, _window, _cx| style.opacity(0.5))
+            .on_drop(cx.listener(move |town, drag: &DraggedTab, _window, cx| {
+                town.split_pane_with_dragged_item(
+                    zone_path.clone(),
+                    direction,
+                    (drag.pane_path.clone(), drag.item_index),
+                    cx,
+                );
+            }))
+            .into_any_element()
+    }
+
+    /// Renders the tab strip for `pane`: each tab shows the item's
+    /// `tab_icon`, `tab_content` label, and a hover tooltip from
+    /// `tab_tooltip_text`. A dirty dot (from `is_dirty`) sits in the same
+    /// slot as a hover-revealed close button, and right-clicking opens the
+    /// bulk-close context menu.
+    fn render_tabs(
+        pane: &CenterPane,
+        path: &PanePath,
+        colors: &ui::ThemeColors,
+        window: &mut Window,
+        cx: &mut Context<Town>,
+    ) -> gpui::AnyElement {
+        div()
+            .id("tab-bar")
+            .relative()
+            .flex()
+            .children(pane.items.iter().enumerate().map(|(index, item)| {
+                let is_active = index == pane.active_index;
+                let tab_path = path.clone();
+                let close_path = path.clone();
+                let context_menu_path = path.clone();
+                let drop_path = path.clone();
+                let params = TabContentParams {
+                    selected: is_active,
+                    deemphasized: false,
+                };
+                let is_dirty = item.is_dirty(cx);
+                let drag_label = item.tab_tooltip_text(cx).unwrap_or_else(|| "Tab".into());
+                let drag_over_background = colors.tab_active_background;
+
+                div()
+                    .id(("tab", index))
+                    .group("tab")
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_3()
+                    .h_8()
+                    .when(is_active, |div| div.bg(colors.tab_active_background))
+                    .when(!is_active, |div| div.bg(colors.tab_inactive_background))
+                    .when_some(item.tab_tooltip_text(cx), |div, text| {
+                        div.tooltip_text(text)
+                    })
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(move |town, _event, _window, cx| {
+                            town.show_tab_context_menu(context_menu_path.clone(), index, cx);
+                        }),
+                    )
+                    .on_drag(
+                        DraggedTab {
+                            pane_path: tab_path.clone(),
+                            item_index: index,
+                        },
+                        move |_drag, _window, cx| {
+                            cx.new(|_| DraggedTabPreview {
+                                label: drag_label.clone(),
+                            })
+                        },
+                    )
+                    .drag_over::<DraggedTab>(move |style, _drag, _window, _cx| {
+                        style.bg(drag_over_background)
+                    })
+                    .on_drop(cx.listener(move |town, drag: &DraggedTab, _window, cx| {
+                        town.move_item(
+                            (drag.pane_path.clone(), drag.item_index),
+                            drop_path.clone(),
+                            index,
+                            cx,
+                        );
+                    }))
+                    .children(item.tab_icon(window, cx))
+                    .child(item.tab_content(params, window, cx))
+                    .child(
+                        div()
+                            .id(("tab-close", index))
+                            .size_3()
+                            .rounded_full()
+                            .when(is_dirty, |div| div.bg(gpui::rgb(0xe2a33d)))
+                            .when(!is_dirty, |div| {
+                                div.invisible().group_hover("tab", |div| div.visible())
+                            })
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |town, _event, window, cx| {
+                                    town.active_pane_path = close_path.clone();
+                                    town.prompt_to_close(index, window, cx).detach();
+                                }),
+                            ),
+                    )
+            }))
+            .when_some(pane.context_menu_index, |div, index| {
+                div.child(Self::render_tab_context_menu(path, index, colors, cx))
+            })
+            .into_any_element()
+    }
+
+    fn render_tab_context_menu(
+        path: &PanePath,
+        index: usize,
+        colors: &ui::ThemeColors,
+        cx: &mut Context<Town>,
+    ) -> gpui::AnyElement {
+        let entry = |label: &'static str,
+                      action: fn(&mut Town, usize, &mut Window, &mut Context<Town>)| {
+            let path = path.clone();
+            div()
+                .id(label)
+                .px_2()
+                .py_1()
+                .hover(|div| div.bg(colors.tab_active_background))
+                .child(label)
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |town, _event, window, cx| {
+                        town.active_pane_path = path.clone();
+                        action(town, index, window, cx);
+                        town.hide_tab_context_menu(cx);
+                    }),
+                )
+        };
+
+        div()
+            .id("tab-context-menu")
+            .absolute()
+            .top_8()
+            .flex()
+            .flex_col()
+            .bg(colors.panel_background)
+            .border_1()
+            .border_color(colors.border)
+            .child(entry("Close", |town, index, window, cx| {
+                town.prompt_to_close(index, window, cx).detach();
+            }))
+            .child(entry("Close Others", |town, index, _window, cx| {
+                town.close_other_items(index, cx);
+            }))
+            .child(entry("Close to the Left", |town, index, _window, cx| {
+                town.close_items_to_the_left(index, cx);
+            }))
+            .child(entry("Close to the Right", |town, index, _window, cx| {
+                town.close_items_to_the_right(index, cx);
+            }))
+            .child(entry("Close All", |town, _index, _window, cx| {
+                town.close_all_items(cx);
+            }))
+            .into_any_element()
+    }
+}
+
+/// Town represents a ~/gt/ workspace.
+///
+/// This is the root entity for Belvedere, analogous to Workspace in Zed.
+/// The center area is a recursive tree of panes (see [`PaneGroup`]) rather
+/// than a single flat list of tabs, so the user can split it arbitrarily.
+pub struct Town {
+    /// Path to the ~/gt/ directory
+    pub path: std::path::PathBuf,
+
+    /// Collection of rig directories
+    pub rigs: HashMap<String, ()>,
+
+    /// Discovered agent instances
+    pub agents: HashMap<String, ()>,
+
+    /// Multi-agent coordination groups
+    pub convoys: HashMap<String, ()>,
+
+    /// Root of the center area's pane tree
+    center: PaneGroup,
+
+    /// Path to the leaf pane that currently has focus
+    active_pane_path: PanePath,
+
+    /// Docked panel areas, keyed by which edge they're attached to
+    docks: HashMap<DockPosition, Dock>,
+
+    /// When to automatically save a dirty `TownItem`.
+    autosave: Autosave,
+
+    /// Pending `AfterDelay` autosave timers, keyed by the pane and item
+    /// index they were scheduled for. A fresh edit replaces the entry,
+    /// dropping (and so cancelling) the previous timer.
+    autosave_tasks: HashMap<(PanePath, usize), Task<()>>,
+
+    /// Last `agent::Doctor` results, shown as a bottom-of-window panel
+    /// when non-empty. Populated by [`Town::set_doctor_reports`].
+    doctor_reports: Vec<crate::agent_doctor::AgentReport>,
+
+    /// Last `rig_vcs::group_by_rig` results, consulted by the doctor panel
+    /// to show each agent's row alongside the branch its rig is on.
+    /// Populated by [`Town::set_rig_contexts`].
+    rig_contexts: Vec<crate::rig_vcs::RigContext>,
+
+    /// Focus handle for keyboard navigation
+    pub focus_handle: FocusHandle,
+}
+
+impl Town {
+    pub fn new(path: std::path::PathBuf, cx: &mut Context<Self>) -> Self {
+        let mut docks = HashMap::default();
+        docks.insert(DockPosition::Left, Dock::new(DockPosition::Left, gpui::px(256.0)));
+        docks.insert(DockPosition::Right, Dock::new(DockPosition::Right, gpui::px(256.0)));
+        docks.insert(DockPosition::Bottom, Dock::new(DockPosition::Bottom, gpui::px(192.0)));
+
+        Self {
+            path,
+            rigs: HashMap::default(),
+            agents: HashMap::default(),
+            convoys: HashMap::default(),
+            center: PaneGroup::Pane(CenterPane::new()),
+            active_pane_path: Vec::new(),
+            docks,
+            autosave: Autosave::Off,
+            autosave_tasks: HashMap::default(),
+            doctor_reports: Vec::new(),
+            rig_contexts: Vec::new(),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Replaces the doctor panel's contents, e.g. after re-running
+    /// `agent::Doctor` over a fresh `AgentDiscovery::discover_agents`
+    /// scan. Pass an empty `Vec` to hide the panel.
+    pub fn set_doctor_reports(
+        &mut self,
+        reports: Vec<crate::agent_doctor::AgentReport>,
+        cx: &mut Context<Self>,
+    ) {
+        self.doctor_reports = reports;
+        cx.notify();
+    }
+
+    /// Replaces the doctor panel's rig/branch context, e.g. after
+    /// re-running `rig_vcs::group_by_rig` over a fresh
+    /// `AgentDiscovery::discover_agents` scan.
+    pub fn set_rig_contexts(
+        &mut self,
+        contexts: Vec<crate::rig_vcs::RigContext>,
+        cx: &mut Context<Self>,
+    ) {
+        self.rig_contexts = contexts;
+        cx.notify();
+    }
+
+    /// The branch name of the rig `agent` belongs to, if its rig is a git
+    /// repository on a named branch.
+    fn branch_for(&self, agent: &crate::agent_discovery::AgentDirectory) -> Option<&str> {
+        let rig_root = crate::rig_vcs::rig_root_of(agent)?;
+        self.rig_contexts
+            .iter()
+            .find(|ctx| ctx.rig_root == rig_root)?
+            .vcs
+            .as_ref()?
+            .branch
+            .as_deref()
+    }
+
+    /// Renders the doctor panel: one row per agent, colored by its worst
+    /// finding's severity, with each finding's message beneath it. Empty
+    /// when there are no reports, e.g. before the first scan.
+    fn render_doctor_panel(&self, cx: &Context<Self>) -> Option<gpui::AnyElement> {
+        if self.doctor_reports.is_empty() {
+            return None;
+        }
+
+        let status = cx.theme().status();
+
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap(gpui::px(4.0))
+                .p(gpui::px(8.0))
+                .max_h(gpui::px(160.0))
+                .overflow_y_scroll()
+                .children(self.doctor_reports.iter().map(|report| {
+                    let severity_color = match report.severity() {
+                        crate::agent_doctor::Severity::Ok => status.success,
+                        crate::agent_doctor::Severity::Warn => status.warning,
+                        crate::agent_doctor::Severity::Error => status.error,
+                    };
+
+                    let branch = self.branch_for(&report.agent).map(|b| format!("({b})"));
+
+                    div()
+                        .flex()
+                        .flex_col()
+                        .child(
+                            div()
+                                .flex()
+                                .gap(gpui::px(6.0))
+                                .child(div().text_color(severity_color).child("●"))
+                                .child(report.agent.instance_name.clone())
+                                .children(branch.map(|b| div().text_sm().child(b))),
+                        )
+                        .children(report.findings.iter().map(|finding| {
+                            div()
+                                .pl(gpui::px(16.0))
+                                .text_sm()
+                                .child(finding.message.clone())
+                        }))
+                }))
+                .into_any_element(),
+        )
+    }
+
+    /// Registers a panel in the dock it declares as its home position.
+    pub fn add_panel(
+        &mut self,
+        view: AnyView,
+        position: DockPosition,
+        icon: gpui::Icon,
+        label: impl Into<gpui::SharedString>,
+    ) {
+        if let Some(dock) = self.docks.get_mut(&position) {
+            dock.add_panel(view, icon, label);
+        }
+    }
+
+    /// Opens or closes the dock at `position`.
+    pub fn toggle_dock(&mut self, position: DockPosition, cx: &mut Context<Self>) {
+        if let Some(dock) = self.docks.get_mut(&position) {
+            dock.toggle_open();
+        }
+        cx.notify();
+    }
+
+    /// Activates the panel at `index` within the dock at `position`, opening
+    /// the dock if it was closed.
+    pub fn activate_panel(&mut self, position: DockPosition, index: usize, cx: &mut Context<Self>) {
+        if let Some(dock) = self.docks.get_mut(&position) {
+            dock.activate_panel(index);
+        }
+        cx.notify();
+    }
+
+    /// Resizes the dock at `position` to `size` pixels.
+    pub fn resize_dock(&mut self, position: DockPosition, size: gpui::Pixels, cx: &mut Context<Self>) {
+        if let Some(dock) = self.docks.get_mut(&position) {
+            dock.resize(size);
+        }
+        cx.notify();
+    }
+
+    /// Sets the policy governing when dirty `TownItem`s are saved automatically.
+    pub fn set_autosave(&mut self, autosave: Autosave) {
+        self.autosave = autosave;
+    }
+
+    fn active_pane(&self) -> &CenterPane {
+        self.center
+            .leaf_at(&self.active_pane_path)
+            .expect("active_pane_path must always resolve to a leaf")
+    }
+
+    fn active_pane_mut(&mut self) -> &mut CenterPane {
+        self.center
+            .leaf_at_mut(&self.active_pane_path)
+            .expect("active_pane_path must always resolve to a leaf")
+    }
+
+    /// Opens a new item in the currently focused pane
+    pub fn open_item(&mut self, item: Box<dyn TownItemHandle>, cx: &mut Context<Self>) {
+        self.active_pane_mut().add_item(item);
+        cx.notify();
+    }
+
+    /// Returns the currently active item in the focused pane
+    pub fn active_item(&self) -> Option<AnyView> {
+        self.active_pane().active_item().map(|item| item.any_view())
+    }
+
+    /// Closes an item at the specified index in the focused pane
+    pub fn close_item(&mut self, index: usize, cx: &mut Context<Self>) -> Option<AnyView> {
+        let removed = self.active_pane_mut().close_item(index, cx);
+        cx.notify();
+        removed
+    }
+
+    /// Closes every closable item to the left of `index` in the focused pane.
+    pub fn close_items_to_the_left(&mut self, index: usize, cx: &mut Context<Self>) -> Vec<AnyView> {
+        let removed = self.active_pane_mut().close_items_to_the_left(index, cx);
+        cx.notify();
+        removed
+    }
+
+    /// Closes every closable item to the right of `index` in the focused pane.
+    pub fn close_items_to_the_right(&mut self, index: usize, cx: &mut Context<Self>) -> Vec<AnyView> {
+        let removed = self.active_pane_mut().close_items_to_the_right(index, cx);
+        cx.notify();
+        removed
+    }
+
+    /// Closes every closable item other than `index` in the focused pane.
+    pub fn close_other_items(&mut self, index: usize, cx: &mut Context<Self>) -> Vec<AnyView> {
+        let removed = self.active_pane_mut().close_other_items(index, cx);
+        cx.notify();
+        removed
+    }
+
+    /// Closes every closable item in the focused pane.
+    pub fn close_all_items(&mut self, cx: &mut Context<Self>) -> Vec<AnyView> {
+        let removed = self.active_pane_mut().close_all_items(cx);
+        cx.notify();
+        removed
+    }
+
+    /// Closes the item at `index` in the focused pane, routing through a
+    /// Save/Discard/Cancel prompt if `TownItem::can_close` refuses to close
+    /// it outright (as a dirty item does), so in-progress agent edits aren't
+    /// silently lost. Items that allow closing close immediately without a
+    /// prompt; the prompt is shown whenever `can_close` refuses, whether or
+    /// not `is_dirty` reports unsaved changes.
+    pub fn prompt_to_close(
+        &mut self,
+        index: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<()> {
+        let pane_path = self.active_pane_path.clone();
+        let Some(item) = self.active_pane().item_at(index) else {
+            return Task::ready(());
+        };
+        if item.can_close(cx) {
+            self.close_item(index, cx);
+            return Task::ready(());
+        }
+        let label = item.tab_tooltip_text(cx).unwrap_or_else(|| "This item".into());
+        let answer = window.prompt(
+            PromptLevel::Warning,
+            &format!("{label} has unsaved changes."),
+            Some("Do you want to save your changes before closing?"),
+            &["Save", "Discard", "Cancel"],
+            cx,
+        );
+
+        cx.spawn(async move |this, cx| {
+            let Ok(choice) = answer.await else { return };
+            if choice == 2 {
+                return;
+            }
+            this.update(cx, |town, cx| {
+                if choice == 0 {
+                    if let Some(item) =
+                        town.center.leaf_at(&pane_path).and_then(|pane| pane.item_at(index))
+                    {
+                        item.save(cx);
+                    }
+                }
+                town.active_pane_path = pane_path;
+                town.force_close_item(index, cx);
+            })
+            .ok();
+        })
+    }
+
+    /// Removes the item at `index` from the focused pane without consulting
+    /// `TownItem::can_close`, for use once the user has confirmed via
+    /// `prompt_to_close`.
+    fn force_close_item(&mut self, index: usize, cx: &mut Context<Self>) {
+        if let Some(item) = self.active_pane_mut().take_item(index) {
+            item.on_removed(cx);
+        }
+        cx.notify();
+    }
+
+    /// Schedules (or reschedules) an `AfterDelay` autosave for the item at
+    /// `index` in the pane at `pane_path`. Repeated calls for the same item
+    /// replace the pending timer, so continued edits push the save back out
+    /// by the full delay each time. No-ops unless `self.autosave` is
+    /// `Autosave::AfterDelay`.
+    fn schedule_autosave(&mut self, pane_path: PanePath, index: usize, cx: &mut Context<Self>) {
+        let Autosave::AfterDelay { millis } = self.autosave else {
+            return;
+        };
+        let delay = Duration::from_millis(millis);
+        let key = (pane_path.clone(), index);
+        let task = cx.spawn({
+            let key = key.clone();
+            async move |this, cx| {
+                Timer::after(delay).await;
+                this.update(cx, |town, cx| {
+                    town.autosave_tasks.remove(&key);
+                    if let Some(item) =
+                        town.center.leaf_at(&pane_path).and_then(|pane| pane.item_at(index))
+                    {
+                        if item.is_dirty(cx) {
+                            item.save(cx);
+                        }
+                    }
+                })
+                .ok();
+            }
+        });
+        self.autosave_tasks.insert(key, task);
+    }
+
+    /// Runs the `OnFocusChange` autosave trigger for the item at `index`
+    /// (the item that was just deactivated) in the focused pane: always
+    /// calls `TownItem::deactivated`, then saves it if it's still dirty and
+    /// `self.autosave` is `Autosave::OnFocusChange`.
+    fn handle_focus_change(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(item) = self.active_pane().item_at(index) else {
+            return;
+        };
+        item.deactivated(window, cx);
+        if matches!(self.autosave, Autosave::OnFocusChange) && item.is_dirty(cx) {
+            item.save(cx);
+        }
+    }
+
+    /// Reacts to a [`TownItemEvent`] forwarded from the item at `index` in
+    /// the pane at `pane_path`.
+    pub fn handle_item_event(
+        &mut self,
+        pane_path: PanePath,
+        index: usize,
+        event: TownItemEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event {
+            TownItemEvent::CloseItem => {
+                self.active_pane_path = pane_path;
+                self.prompt_to_close(index, window, cx).detach();
+            }
+            TownItemEvent::UpdateTab => cx.notify(),
+            TownItemEvent::Edit => self.schedule_autosave(pane_path, index, cx),
+        }
+    }
+
+    /// Focuses the pane at `path` and opens its tab `index`'s right-click
+    /// context menu.
+    fn show_tab_context_menu(&mut self, pane_path: PanePath, index: usize, cx: &mut Context<Self>) {
+        self.active_pane_path = pane_path;
+        self.active_pane_mut().context_menu_index = Some(index);
+        cx.notify();
+    }
+
+    /// Closes the currently open tab context menu, if any.
+    fn hide_tab_context_menu(&mut self, cx: &mut Context<Self>) {
+        self.active_pane_mut().context_menu_index = None;
+        cx.notify();
+    }
+
+    /// Sets the active item by index in the focused pane, running the
+    /// `OnFocusChange` autosave trigger on the item that was deactivated.
+    pub fn set_active_item(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(previous) = self.active_pane_mut().set_active(index) {
+            self.handle_focus_change(previous, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Whether `go_back` has an entry to return to in the focused pane.
+    pub fn can_go_back(&self) -> bool {
+        self.active_pane().can_go_back()
+    }
+
+    /// Whether `go_forward` has an entry to return to in the focused pane.
+    pub fn can_go_forward(&self) -> bool {
+        self.active_pane().can_go_forward()
+    }
+
+    /// Re-activates the previously active item in the focused pane (ctrl-o).
+    pub fn go_back(&mut self, cx: &mut Context<Self>) {
+        self.active_pane_mut().go_back();
+        cx.notify();
+    }
+
+    /// Re-activates the item `go_back` most recently moved away from (ctrl-i).
+    pub fn go_forward(&mut self, cx: &mut Context<Self>) {
+        self.active_pane_mut().go_forward();
+        cx.notify();
+    }
+
+    /// Splits the currently focused pane, moving focus to the new empty pane.
+    pub fn split_active_pane(&mut self, direction: SplitDirection, cx: &mut Context<Self>) {
+        // `split_at` returns the new pane's path from the root it was called on.
+        if let Some(new_path) = self.center.split_at(&self.active_pane_path, direction) {
+            self.active_pane_path = new_path;
+        }
+        cx.notify();
+    }
+
+    /// Removes the focused pane if it's empty, collapsing the layout and
+    /// moving focus to the first (top/left-most) remaining leaf pane.
+    pub fn close_pane(&mut self, cx: &mut Context<Self>) {
+        self.center.close_at(&self.active_pane_path);
+        let mut first_leaf = Vec::new();
+        let mut node = &self.center;
+        while let PaneGroup::Split { children, .. } = node {
+            first_leaf.push(0);
+            node = &children[0];
+        }
+        self.active_pane_path = first_leaf;
+        cx.notify();
+    }
+
+    /// Moves a dragged tab from `from` (its source pane and index there) to
+    /// `insert_index` within the pane at `to`, focusing the moved item.
+    /// Collapses the source pane if dragging its last tab out emptied it.
+    pub fn move_item(
+        &mut self,
+        from: (PanePath, usize),
+        to: PanePath,
+        insert_index: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let (from_pane, item_index) = from;
+        let Some(item) = self
+            .center
+            .leaf_at_mut(&from_pane)
+            .and_then(|pane| pane.take_item(item_index))
+        else {
+            return;
+        };
+
+        match self.center.leaf_at_mut(&to) {
+            Some(dest) => {
+                dest.insert_item_at(insert_index, item);
+            }
+            None => {
+                // Destination path no longer resolves; put the item back
+                // rather than lose it silently.
+                if let Some(pane) = self.center.leaf_at_mut(&from_pane) {
+                    pane.insert_item_at(item_index, item);
+                }
+                cx.notify();
+                return;
+            }
+        }
+
+        // `from_pane` may collapse below, shifting the indices of its later
+        // siblings down by one - remap `to` before committing it so it still
+        // resolves to the pane the item actually landed in.
+        let will_collapse = self.center.leaf_at(&from_pane).is_some_and(CenterPane::is_empty);
+        self.active_pane_path = if will_collapse {
+            remap_after_sibling_removal(&from_pane, &to)
+        } else {
+            to
+        };
+        self.collapse_if_empty(&from_pane);
+        cx.notify();
+    }
+
+    /// Splits the pane at `path` in `direction`, moving the dragged tab
+    /// identified by `from` into the new pane as its sole item, and
+    /// collapsing the source pane if that emptied it.
+    pub fn split_pane_with_dragged_item(
+        &mut self,
+        path: PanePath,
+        direction: SplitDirection,
+        from: (PanePath, usize),
+        cx: &mut Context<Self>,
+    ) {
+        let (from_pane, item_index) = from;
+        let Some(item) = self
+            .center
+            .leaf_at_mut(&from_pane)
+            .and_then(|pane| pane.take_item(item_index))
+        else {
+            return;
+        };
+
+        if let Some(new_path) = self.center.split_at(&path, direction) {
+            if let Some(new_pane) = self.center.leaf_at_mut(&new_path) {
+                new_pane.insert_item_at(0, item);
+            }
+
+            // `from_pane` may collapse below, shifting the indices of its
+            // later siblings down by one - remap `new_path` before
+            // committing it, same as `move_item` does.
+            let will_collapse = self.center.leaf_at(&from_pane).is_some_and(CenterPane::is_empty);
+            self.active_pane_path = if will_collapse {
+                remap_after_sibling_removal(&from_pane, &new_path)
+            } else {
+                new_path
+            };
+        } else if let Some(pane) = self.center.leaf_at_mut(&from_pane) {
+            // The target path didn't resolve to a leaf pane, so nothing was
+            // split; put the item back.
+            pane.insert_item_at(item_index, item);
+        }
+
+        self.collapse_if_empty(&from_pane);
+        cx.notify();
+    }
+
+    /// Collapses the pane at `path` if dragging a tab out of it left it
+    /// empty, moving focus to the first (top/left-most) remaining leaf pane
+    /// if it was the focused one.
+    ///
+    /// Callers that set `active_pane_path` to some other path before calling
+    /// this must first remap it with [`remap_after_sibling_removal`], since
+    /// removing `path` here can shift the index of a later sibling under the
+    /// same split.
+    fn collapse_if_empty(&mut self, path: &PanePath) {
+        if self.center.leaf_at(path).is_some_and(CenterPane::is_empty) {
+            self.center.close_at(path);
+            if self.active_pane_path == *path {
+                let mut first_leaf = Vec::new();
+                let mut node = &self.center;
+                while let PaneGroup::Split { children, .. } = node {
+                    first_leaf.push(0);
+                    node = &children[0];
+                }
+                self.active_pane_path = first_leaf;
+            }
+        }
+    }
+
+    fn render_center(&self, window: &mut Window, cx: &mut Context<Self>) -> gpui::AnyElement {
+        let colors = cx.theme().colors().clone();
+        self.center.render(&Vec::new(), &colors, window, cx)
+    }
+}
+
+impl Focusable for Town {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl gpui::EventEmitter<()> for Town {}
+
+impl Render for Town {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors().clone();
+        let left_dock = self
+            .docks
+            .get(&DockPosition::Left)
+            .map(|dock| dock.render(&colors));
+        let right_dock = self
+            .docks
+            .get(&DockPosition::Right)
+            .map(|dock| dock.render(&colors));
+        let bottom_dock = self
+            .docks
+            .get(&DockPosition::Bottom)
+            .map(|dock| dock.render(&colors));
+        let doctor_panel = self.render_doctor_panel(cx);
+
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(colors.editor_background)
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_1()
+                    .min_h_0()
+                    .children(left_dock)
+                    .child(self.render_center(window, cx))
+                    .children(right_dock),
+            )
+            .children(bottom_dock)
+            .children(doctor_panel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(direction: SplitDirection, children: Vec<PaneGroup>) -> PaneGroup {
+        let even_share = 1.0 / children.len() as f32;
+        let sizes = vec![even_share; children.len()];
+        PaneGroup::Split {
+            direction,
+            children,
+            sizes,
+        }
+    }
+
+    fn pane() -> PaneGroup {
+        PaneGroup::Pane(CenterPane::new())
+    }
+
+    #[test]
+    fn test_remap_after_sibling_removal_decrements_later_sibling() {
+        let removed = vec![0];
+        let path = vec![2];
+        assert_eq!(remap_after_sibling_removal(&removed, &path), vec![1]);
+    }
+
+    #[test]
+    fn test_remap_after_sibling_removal_leaves_earlier_sibling_alone() {
+        let removed = vec![2];
+        let path = vec![0];
+        assert_eq!(remap_after_sibling_removal(&removed, &path), vec![0]);
+    }
+
+    #[test]
+    fn test_remap_after_sibling_removal_ignores_different_parents() {
+        let removed = vec![0, 1];
+        let path = vec![1, 0];
+        assert_eq!(remap_after_sibling_removal(&removed, &path), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_remap_after_sibling_removal_ignores_non_sibling_depth() {
+        let removed = vec![0];
+        let path = vec![0, 1];
+        assert_eq!(remap_after_sibling_removal(&removed, &path), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_leaf_at_resolves_nested_path() {
+        let root = split(SplitDirection::Horizontal, vec![pane(), pane()]);
+        assert!(root.leaf_at(&[0]).is_some());
+        assert!(root.leaf_at(&[1]).is_some());
+        assert!(root.leaf_at(&[2]).is_none());
+    }
+
+    #[test]
+    fn test_close_at_removes_empty_leaf_and_shifts_siblings() {
+        let mut root = split(SplitDirection::Horizontal, vec![pane(), pane(), pane()]);
+        root.close_at(&[0]);
+        match &root {
+            PaneGroup::Split { children, sizes, .. } => {
+                assert_eq!(children.len(), 2);
+                assert_eq!(sizes.len(), 2);
+            }
+            PaneGroup::Pane(_) => panic!("expected split to remain with two children"),
+        }
+    }
+
+    #[test]
+    fn test_close_at_collapses_split_to_bare_pane() {
+        let mut root = split(SplitDirection::Horizontal, vec![pane(), pane()]);
+        root.close_at(&[0]);
+        assert!(matches!(root, PaneGroup::Pane(_)));
+    }
+
+    #[test]
+    fn test_split_at_root_creates_split_with_new_sibling() {
+        let mut root = pane();
+        let new_path = root.split_at(&[], SplitDirection::Vertical).unwrap();
+        assert_eq!(new_path, vec![1]);
+        assert!(matches!(root, PaneGroup::Split { .. }));
+    }
+
+    #[test]
+    fn test_split_at_reuses_existing_split_in_same_direction() {
+        let mut root = split(SplitDirection::Horizontal, vec![pane(), pane()]);
+        let new_path = root
+            .split_at(&[1], SplitDirection::Horizontal)
+            .unwrap();
+        assert_eq!(new_path, vec![2]);
+        match &root {
+            PaneGroup::Split { children, .. } => assert_eq!(children.len(), 3),
+            PaneGroup::Pane(_) => panic!("expected split"),
+        }
+    }
+}
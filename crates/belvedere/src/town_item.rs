@@ -1,5 +1,6 @@
 use gpui::{
-    AnyElement, App, Context, EventEmitter, Focusable, IntoElement, Render, SharedString, Window,
+    AnyElement, AnyView, App, Context, Entity, EventEmitter, Focusable, IntoElement, Render,
+    SharedString, Window,
 };
 use ui::{Color, Icon, Label, LabelCommon};
 
@@ -92,4 +93,84 @@ pub trait TownItem: Focusable + EventEmitter<Self::Event> + Render + Sized {
     fn can_close(&self, _cx: &App) -> bool {
         true
     }
+
+    /// Persists this item's unsaved changes.
+    ///
+    /// Called by `Town`'s autosave subsystem and by `Town::prompt_to_close`
+    /// when the user chooses to save a dirty item before closing. The
+    /// default no-op is fine for items that are never dirty.
+    fn save(&mut self, _cx: &mut Context<Self>) {}
+}
+
+/// Object-safe handle over a concrete [`TownItem`] entity.
+///
+/// `TownItem` takes `Self: Sized` plus an associated `Event` type, so it
+/// can't be used as `dyn TownItem`. `CenterPane` stores tabs as
+/// `Box<dyn TownItemHandle>` instead, dispatching into the underlying
+/// entity without needing to know its concrete type.
+pub trait TownItemHandle {
+    /// Erases this handle back down to a plain view for rendering.
+    fn any_view(&self) -> AnyView;
+
+    /// See [`TownItem::is_dirty`].
+    fn is_dirty(&self, cx: &App) -> bool;
+
+    /// See [`TownItem::can_close`].
+    fn can_close(&self, cx: &App) -> bool;
+
+    /// See [`TownItem::save`].
+    fn save(&self, cx: &mut App);
+
+    /// See [`TownItem::deactivated`].
+    fn deactivated(&self, window: &mut Window, cx: &mut App);
+
+    /// See [`TownItem::on_removed`].
+    fn on_removed(&self, cx: &App);
+
+    /// See [`TownItem::tab_content`].
+    fn tab_content(&self, params: TabContentParams, window: &Window, cx: &App) -> AnyElement;
+
+    /// See [`TownItem::tab_icon`].
+    fn tab_icon(&self, window: &Window, cx: &App) -> Option<Icon>;
+
+    /// See [`TownItem::tab_tooltip_text`].
+    fn tab_tooltip_text(&self, cx: &App) -> Option<SharedString>;
+}
+
+impl<T: TownItem> TownItemHandle for Entity<T> {
+    fn any_view(&self) -> AnyView {
+        self.clone().into()
+    }
+
+    fn is_dirty(&self, cx: &App) -> bool {
+        self.read(cx).is_dirty(cx)
+    }
+
+    fn can_close(&self, cx: &App) -> bool {
+        self.read(cx).can_close(cx)
+    }
+
+    fn save(&self, cx: &mut App) {
+        self.update(cx, |item, cx| item.save(cx));
+    }
+
+    fn deactivated(&self, window: &mut Window, cx: &mut App) {
+        self.update(cx, |item, cx| item.deactivated(window, cx));
+    }
+
+    fn on_removed(&self, cx: &App) {
+        self.read(cx).on_removed(cx)
+    }
+
+    fn tab_content(&self, params: TabContentParams, window: &Window, cx: &App) -> AnyElement {
+        self.read(cx).tab_content(params, window, cx)
+    }
+
+    fn tab_icon(&self, window: &Window, cx: &App) -> Option<Icon> {
+        self.read(cx).tab_icon(window, cx)
+    }
+
+    fn tab_tooltip_text(&self, cx: &App) -> Option<SharedString> {
+        self.read(cx).tab_tooltip_text(cx)
+    }
 }
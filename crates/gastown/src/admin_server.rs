@@ -0,0 +1,203 @@
+#![allow(dead_code)]
+
+//! Optional embedded HTTP server exposing the dashboard's current state to
+//! external tooling: a JSON snapshot and a Prometheus metrics endpoint.
+//!
+//! Started from `main` behind the `GASTOWN_ADMIN_ADDR` env var, this reuses
+//! the same `Arc<dyn DashboardDataSource>` the UI renders from, so both read
+//! one source of truth. Turns the dashboard into a scrapeable control-plane
+//! surface rather than a view-only window.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::thread;
+
+use crate::dashboard_buffer::{AgentStatus, DashboardData, DashboardDataSource, DashboardError};
+
+/// Starts the admin HTTP server on its own background thread, listening on
+/// `addr` (e.g. `127.0.0.1:9090`). Serves `GET /dashboard.json` and
+/// `GET /metrics` against `data_source`; the thread runs for the life of the
+/// process.
+pub fn spawn(
+    addr: impl Into<String>,
+    data_source: Arc<dyn DashboardDataSource>,
+) -> Result<(), DashboardError> {
+    let addr = addr.into();
+    let server =
+        tiny_http::Server::http(&addr).map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+    thread::Builder::new()
+        .name("gastown-admin-http".into())
+        .spawn(move || serve(server, data_source))
+        .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+    tracing::info!(%addr, "admin HTTP server listening");
+    Ok(())
+}
+
+fn serve(server: tiny_http::Server, data_source: Arc<dyn DashboardDataSource>) {
+    for request in server.incoming_requests() {
+        let response = match request.url() {
+            "/dashboard.json" => dashboard_json_response(&data_source),
+            "/metrics" => metrics_response(&data_source),
+            _ => not_found_response(),
+        };
+
+        if let Err(err) = request.respond(response) {
+            tracing::warn!(error = %err, "admin HTTP response failed");
+        }
+    }
+}
+
+fn dashboard_json_response(data_source: &Arc<dyn DashboardDataSource>) -> HttpResponse {
+    match data_source.fetch() {
+        Ok(data) => match data.to_json() {
+            Ok(json) => json_response(200, json),
+            Err(err) => json_error_response(500, &err.to_string()),
+        },
+        Err(err) => json_error_response(503, &err.to_string()),
+    }
+}
+
+fn metrics_response(data_source: &Arc<dyn DashboardDataSource>) -> HttpResponse {
+    match data_source.fetch() {
+        Ok(data) => {
+            let header =
+                tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"text/plain; version=0.0.4"[..])
+                    .expect("static header is valid");
+            tiny_http::Response::from_string(render_prometheus_metrics(&data)).with_header(header)
+        }
+        Err(err) => tiny_http::Response::from_string(format!("# fetch failed: {err}\n"))
+            .with_status_code(503),
+    }
+}
+
+type HttpResponse = tiny_http::Response<Cursor<Vec<u8>>>;
+
+fn json_response(status: u16, body: String) -> HttpResponse {
+    let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("static header is valid");
+    tiny_http::Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header)
+}
+
+fn json_error_response(status: u16, message: &str) -> HttpResponse {
+    json_response(status, serde_json::json!({ "error": message }).to_string())
+}
+
+fn not_found_response() -> HttpResponse {
+    tiny_http::Response::from_string("not found").with_status_code(404)
+}
+
+/// Renders `data` as Prometheus text-format gauges: per-agent context fill,
+/// input tokens, and up/down status, plus per-convoy progress.
+fn render_prometheus_metrics(data: &DashboardData) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP gastown_agent_up Whether the agent is not in an error state.\n");
+    out.push_str("# TYPE gastown_agent_up gauge\n");
+    for agent in &data.agents {
+        let up = if matches!(agent.status, AgentStatus::Error(_)) {
+            0
+        } else {
+            1
+        };
+        out.push_str(&format!(
+            "gastown_agent_up{{agent=\"{}\"}} {}\n",
+            agent.name, up
+        ));
+    }
+
+    out.push_str("# HELP gastown_agent_context_fill Agent context window fill ratio (0-1).\n");
+    out.push_str("# TYPE gastown_agent_context_fill gauge\n");
+    for agent in &data.agents {
+        if let Some(fill) = agent.context_fill {
+            out.push_str(&format!(
+                "gastown_agent_context_fill{{agent=\"{}\"}} {}\n",
+                agent.name, fill
+            ));
+        }
+    }
+
+    out.push_str("# HELP gastown_agent_input_tokens Cumulative input tokens consumed by the agent.\n");
+    out.push_str("# TYPE gastown_agent_input_tokens gauge\n");
+    for agent in &data.agents {
+        if let Some(tokens) = &agent.token_usage {
+            out.push_str(&format!(
+                "gastown_agent_input_tokens{{agent=\"{}\"}} {}\n",
+                agent.name, tokens.input_tokens
+            ));
+        }
+    }
+
+    out.push_str("# HELP gastown_convoy_progress Convoy completion progress (0-1).\n");
+    out.push_str("# TYPE gastown_convoy_progress gauge\n");
+    for convoy in &data.convoys {
+        out.push_str(&format!(
+            "gastown_convoy_progress{{convoy=\"{}\"}} {}\n",
+            convoy.id, convoy.progress
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dashboard_buffer::{AgentInfo, ConvoyInfo, TokenUsage};
+
+    fn sample_data() -> DashboardData {
+        DashboardData {
+            agents: vec![
+                AgentInfo {
+                    name: "agent-1".into(),
+                    status: AgentStatus::Active,
+                    token_usage: Some(TokenUsage {
+                        input_tokens: 100,
+                        output_tokens: 50,
+                        model: None,
+                    }),
+                    context_fill: Some(0.4),
+                    token_usage_history: vec![],
+                },
+                AgentInfo {
+                    name: "agent-2".into(),
+                    status: AgentStatus::Error("boom".into()),
+                    token_usage: None,
+                    context_fill: None,
+                    token_usage_history: vec![],
+                },
+            ],
+            convoys: vec![ConvoyInfo {
+                id: "convoy-1".into(),
+                progress: 0.75,
+                operation: None,
+            }],
+            rigs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_metrics_include_agent_up_and_down() {
+        let metrics = render_prometheus_metrics(&sample_data());
+        assert!(metrics.contains("gastown_agent_up{agent=\"agent-1\"} 1"));
+        assert!(metrics.contains("gastown_agent_up{agent=\"agent-2\"} 0"));
+    }
+
+    #[test]
+    fn test_metrics_include_context_fill_and_tokens() {
+        let metrics = render_prometheus_metrics(&sample_data());
+        assert!(metrics.contains("gastown_agent_context_fill{agent=\"agent-1\"} 0.4"));
+        assert!(metrics.contains("gastown_agent_input_tokens{agent=\"agent-1\"} 100"));
+        // agent-2 has no usage/fill recorded, so it shouldn't appear in those series.
+        assert!(!metrics.contains("gastown_agent_context_fill{agent=\"agent-2\"}"));
+    }
+
+    #[test]
+    fn test_metrics_include_convoy_progress() {
+        let metrics = render_prometheus_metrics(&sample_data());
+        assert!(metrics.contains("gastown_convoy_progress{convoy=\"convoy-1\"} 0.75"));
+    }
+}
@@ -1,18 +1,59 @@
 use gpui::{
-    ClickEvent, Hsla, InteractiveElement, IntoElement, ParentElement, StatefulInteractiveElement,
-    Styled, div, px,
+    App, ClickEvent, Hsla, InteractiveElement, IntoElement, KeyDownEvent, MouseButton,
+    ParentElement, StatefulInteractiveElement, Styled, Window, div, px,
 };
 use std::sync::Arc;
+use ui::ActiveTheme;
 
 use crate::dashboard_buffer::{AgentInfo, AgentStatus};
+use crate::fuzzy::{self, FuzzyMatch};
 
 type ToggleHandler = Arc<dyn Fn(&ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static>;
+type ContextMenuRequestHandler = Arc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+type AgentActionHandler = Arc<dyn Fn(&AgentInfo, AgentAction, &mut Window, &mut App) + 'static>;
+type FilterChangeHandler = Arc<dyn Fn(String, &mut Window, &mut App) + 'static>;
+
+/// Actions an `AgentRow`'s right-click context menu can emit for a running
+/// agent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AgentAction {
+    Pause,
+    Resume,
+    Stop,
+    Restart,
+    CopyTokenStats,
+}
+
+impl AgentAction {
+    fn label(self) -> &'static str {
+        match self {
+            AgentAction::Pause => "Pause",
+            AgentAction::Resume => "Resume",
+            AgentAction::Stop => "Stop",
+            AgentAction::Restart => "Restart",
+            AgentAction::CopyTokenStats => "Copy Token Stats",
+        }
+    }
+}
 
 pub struct AgentSection {
     agents: Vec<AgentInfo>,
     palette: AgentSectionPalette,
     expanded: bool,
     on_toggle: Option<ToggleHandler>,
+    /// Name of the agent whose context menu is currently open, if any. The
+    /// section itself is stateless - the owning view is expected to track
+    /// this (set via `on_context_menu_requested`) and pass it back in on
+    /// the next render, the same way it would own `expanded`.
+    context_menu_agent: Option<String>,
+    on_context_menu_requested: Option<ContextMenuRequestHandler>,
+    on_agent_action: Option<AgentActionHandler>,
+    /// Whether the header renders a filter field. The query text itself is
+    /// owned by the caller and handed back in each render, the same way
+    /// `expanded` and `context_menu_agent` are.
+    filterable: bool,
+    filter_query: String,
+    on_filter_change: Option<FilterChangeHandler>,
 }
 
 #[derive(Clone, Copy)]
@@ -28,6 +69,28 @@ pub struct AgentSectionPalette {
     pub element_bg: Hsla,
 }
 
+impl AgentSectionPalette {
+    /// Pulls the palette from the app's active theme, so agent status
+    /// colors stay consistent with the rest of the UI and restyle on
+    /// theme switch without rebuilding the section.
+    pub fn from_theme(cx: &App) -> Self {
+        let colors = cx.theme().colors();
+        let status = cx.theme().status();
+
+        Self {
+            panel_bg: colors.panel_background,
+            border_variant: colors.border_variant,
+            text: colors.text,
+            text_muted: colors.text_muted,
+            accent_success: status.success,
+            accent_warning: status.warning,
+            accent_error: status.error,
+            accent_info: status.info,
+            element_bg: colors.element_background,
+        }
+    }
+}
+
 impl AgentSection {
     pub fn new(agents: &[AgentInfo], palette: AgentSectionPalette) -> Self {
         Self {
@@ -35,6 +98,12 @@ impl AgentSection {
             palette,
             expanded: true,
             on_toggle: None,
+            context_menu_agent: None,
+            on_context_menu_requested: None,
+            on_agent_action: None,
+            filterable: false,
+            filter_query: String::new(),
+            on_filter_change: None,
         }
     }
 
@@ -50,6 +119,56 @@ impl AgentSection {
         self.on_toggle = Some(Arc::new(on_toggle));
         self
     }
+
+    /// Names the agent whose context menu should render open, mirroring
+    /// `expanded`: the owner tracks this state and passes it back in.
+    pub fn context_menu_agent(mut self, agent_name: Option<String>) -> Self {
+        self.context_menu_agent = agent_name;
+        self
+    }
+
+    /// Called with an agent's name when a row is right-clicked, so the
+    /// owner can open that agent's context menu on the next render.
+    pub fn on_context_menu_requested(
+        mut self,
+        handler: impl Fn(&str, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_context_menu_requested = Some(Arc::new(handler));
+        self
+    }
+
+    /// Called with the chosen `AgentAction` when a context menu entry is
+    /// clicked.
+    pub fn on_agent_action(
+        mut self,
+        handler: impl Fn(&AgentInfo, AgentAction, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_agent_action = Some(Arc::new(handler));
+        self
+    }
+
+    /// Enables the header filter field. Has no effect on which rows show
+    /// until the owner also passes a non-empty `filter_query`.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// The filter field's current text, fuzzy-matched against agent names
+    /// to narrow and rank the displayed rows.
+    pub fn filter_query(mut self, filter_query: impl Into<String>) -> Self {
+        self.filter_query = filter_query.into();
+        self
+    }
+
+    /// Called with the filter field's updated text on every keystroke.
+    pub fn on_filter_change(
+        mut self,
+        handler: impl Fn(String, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_filter_change = Some(Arc::new(handler));
+        self
+    }
 }
 
 impl IntoElement for AgentSection {
@@ -58,6 +177,19 @@ impl IntoElement for AgentSection {
     fn into_element(self) -> Self::Element {
         let palette = self.palette;
         let disclosure = if self.expanded { "▾" } else { "▸" };
+        let context_menu_agent = self.context_menu_agent;
+        let on_context_menu_requested = self.on_context_menu_requested;
+        let on_agent_action = self.on_agent_action;
+
+        let matches: Option<Vec<(&AgentInfo, FuzzyMatch)>> = if self.filterable
+            && !self.filter_query.is_empty()
+        {
+            Some(fuzzy::rank(&self.agents, &self.filter_query, |a| {
+                a.name.as_str()
+            }))
+        } else {
+            None
+        };
 
         let items: Vec<gpui::AnyElement> = if self.agents.is_empty() {
             vec![
@@ -67,10 +199,39 @@ impl IntoElement for AgentSection {
                     .child("No agents running")
                     .into_any_element(),
             ]
+        } else if let Some(matches) = &matches {
+            if matches.is_empty() {
+                vec![
+                    div()
+                        .text_color(palette.text_muted)
+                        .text_sm()
+                        .child("No matches")
+                        .into_any_element(),
+                ]
+            } else {
+                matches
+                    .iter()
+                    .map(|(agent, m)| {
+                        let menu_open =
+                            context_menu_agent.as_deref() == Some(agent.name.as_str());
+                        AgentRow::new((*agent).clone(), palette, menu_open)
+                            .highlighted_indices(m.matched_indices.clone())
+                            .on_context_menu_requested(on_context_menu_requested.clone())
+                            .on_agent_action(on_agent_action.clone())
+                            .into_any_element()
+                    })
+                    .collect()
+            }
         } else {
             self.agents
                 .iter()
-                .map(|agent| AgentRow::new(agent.clone(), palette).into_any_element())
+                .map(|agent| {
+                    let menu_open = context_menu_agent.as_deref() == Some(agent.name.as_str());
+                    AgentRow::new(agent.clone(), palette, menu_open)
+                        .on_context_menu_requested(on_context_menu_requested.clone())
+                        .on_agent_action(on_agent_action.clone())
+                        .into_any_element()
+                })
                 .collect()
         };
 
@@ -91,6 +252,12 @@ impl IntoElement for AgentSection {
             header
         };
 
+        let header = if self.filterable {
+            header.child(FilterInput::new(self.filter_query, self.on_filter_change))
+        } else {
+            header
+        };
+
         let section = div()
             .flex()
             .flex_col()
@@ -110,17 +277,135 @@ impl IntoElement for AgentSection {
     }
 }
 
-struct AgentRow {
+/// Text field in the agents-section header used to type a fuzzy filter
+/// query. Like `AgentSection` itself, this holds no persistent state: the
+/// query text is owned by the caller and handed back in via `query` on
+/// every render, with edits reported through `on_change`.
+struct FilterInput {
+    query: String,
+    on_change: Option<FilterChangeHandler>,
+}
+
+impl FilterInput {
+    fn new(query: String, on_change: Option<FilterChangeHandler>) -> Self {
+        Self { query, on_change }
+    }
+}
+
+impl IntoElement for FilterInput {
+    type Element = gpui::Div;
+
+    fn into_element(self) -> Self::Element {
+        let placeholder = self.query.is_empty();
+        let label = if placeholder {
+            "Filter agents...".to_string()
+        } else {
+            self.query.clone()
+        };
+
+        let mut field = div()
+            .id("agents-filter-input")
+            .ml_auto()
+            .px(px(6.0))
+            .rounded(px(4.0))
+            .text_sm()
+            .child(label);
+
+        if let Some(on_change) = self.on_change {
+            let query = self.query;
+            field = field.on_key_down(move |event: &KeyDownEvent, window, cx| {
+                let mut updated = query.clone();
+                match event.keystroke.key.as_str() {
+                    "backspace" => {
+                        updated.pop();
+                    }
+                    key if key.chars().count() == 1 => {
+                        updated.push_str(key);
+                    }
+                    _ => return,
+                }
+                on_change(updated, window, cx);
+            });
+        }
+
+        field
+    }
+}
+
+pub(crate) struct AgentRow {
     agent: AgentInfo,
     palette: AgentSectionPalette,
+    menu_open: bool,
+    highlighted_indices: Vec<usize>,
+    on_context_menu_requested: Option<ContextMenuRequestHandler>,
+    on_agent_action: Option<AgentActionHandler>,
 }
 
 impl AgentRow {
-    fn new(agent: AgentInfo, palette: AgentSectionPalette) -> Self {
-        Self { agent, palette }
+    pub(crate) fn new(agent: AgentInfo, palette: AgentSectionPalette, menu_open: bool) -> Self {
+        Self {
+            agent,
+            palette,
+            menu_open,
+            highlighted_indices: Vec::new(),
+            on_context_menu_requested: None,
+            on_agent_action: None,
+        }
+    }
+
+    /// Character positions in `agent.name` that matched a fuzzy filter
+    /// query, rendered in an accent color. Empty means render the name
+    /// plainly.
+    fn highlighted_indices(mut self, highlighted_indices: Vec<usize>) -> Self {
+        self.highlighted_indices = highlighted_indices;
+        self
+    }
+
+    fn on_context_menu_requested(mut self, handler: Option<ContextMenuRequestHandler>) -> Self {
+        self.on_context_menu_requested = handler;
+        self
+    }
+
+    fn on_agent_action(mut self, handler: Option<AgentActionHandler>) -> Self {
+        self.on_agent_action = handler;
+        self
+    }
+}
+
+/// Renders `name` as a row of single-character elements, coloring the
+/// characters at `highlighted_indices` with `accent` and the rest with
+/// `base`. Falls back to a single plain child when there's nothing to
+/// highlight.
+fn render_highlighted_name(
+    name: &str,
+    highlighted_indices: &[usize],
+    base: gpui::Hsla,
+    accent: gpui::Hsla,
+) -> gpui::Div {
+    if highlighted_indices.is_empty() {
+        return div().text_color(base).flex_shrink_0().child(name.to_string());
+    }
+
+    let mut row = div().flex().flex_shrink_0();
+    for (index, ch) in name.chars().enumerate() {
+        let color = if highlighted_indices.contains(&index) {
+            accent
+        } else {
+            base
+        };
+        row = row.child(div().text_color(color).child(ch.to_string()));
     }
+    row
 }
 
+const AGENT_ACTIONS: [AgentAction; 5] = [
+    AgentAction::Pause,
+    AgentAction::Resume,
+    AgentAction::Stop,
+    AgentAction::Restart,
+    AgentAction::CopyTokenStats,
+];
+
 impl IntoElement for AgentRow {
     type Element = gpui::Div;
 
@@ -135,6 +420,8 @@ impl IntoElement for AgentRow {
         };
 
         let mut row = div()
+            .id(gpui::SharedString::from(format!("agent-row-{}", agent.name)))
+            .relative()
             .flex()
             .items_center()
             .gap(px(8.0))
@@ -142,17 +429,33 @@ impl IntoElement for AgentRow {
             .px(px(4.0))
             .rounded(px(4.0))
             .child(div().text_color(status_color).child(status_icon))
-            .child(
+            .child(render_highlighted_name(
+                &agent.name,
+                &self.highlighted_indices,
+                palette.text,
+                palette.accent_info,
+            ));
+
+        if let AgentStatus::Error(message) = &agent.status {
+            row = row.child(
                 div()
-                    .text_color(palette.text)
-                    .flex_shrink_0()
-                    .child(agent.name.clone()),
+                    .text_color(palette.accent_error)
+                    .text_sm()
+                    .child(message.clone()),
             );
+        }
 
         if let Some(fill) = agent.context_fill {
             row = row.child(ContextBar::new(fill, palette));
         }
 
+        if agent.token_usage_history.len() >= 2 {
+            row = row.child(TokenSparkline::new(
+                agent.token_usage_history.clone(),
+                palette,
+            ));
+        }
+
         if let Some(ref tokens) = agent.token_usage {
             row = row.child(
                 div()
@@ -166,18 +469,100 @@ impl IntoElement for AgentRow {
             );
         }
 
+        if let Some(on_context_menu_requested) = self.on_context_menu_requested.clone() {
+            let agent_name = agent.name.clone();
+            row = row.on_mouse_down(
+                MouseButton::Right,
+                move |_event, window, cx| on_context_menu_requested(&agent_name, window, cx),
+            );
+        }
+
+        if self.menu_open {
+            row = row.child(AgentContextMenu::new(
+                agent,
+                palette,
+                self.on_agent_action.clone(),
+            ));
+        }
+
         row
     }
 }
 
-struct ContextBar {
+struct AgentContextMenu {
+    agent: AgentInfo,
+    palette: AgentSectionPalette,
+    on_agent_action: Option<AgentActionHandler>,
+}
+
+impl AgentContextMenu {
+    fn new(
+        agent: AgentInfo,
+        palette: AgentSectionPalette,
+        on_agent_action: Option<AgentActionHandler>,
+    ) -> Self {
+        Self {
+            agent,
+            palette,
+            on_agent_action,
+        }
+    }
+}
+
+impl IntoElement for AgentContextMenu {
+    type Element = gpui::Div;
+
+    fn into_element(self) -> Self::Element {
+        let palette = self.palette;
+        let agent = Arc::new(self.agent);
+        let on_agent_action = self.on_agent_action;
+
+        div()
+            .id(gpui::SharedString::from(format!(
+                "agent-context-menu-{}",
+                agent.name
+            )))
+            .absolute()
+            .top_8()
+            .right_0()
+            .flex()
+            .flex_col()
+            .bg(palette.panel_bg)
+            .border_1()
+            .border_color(palette.border_variant)
+            .rounded(px(4.0))
+            .children(AGENT_ACTIONS.into_iter().map(|action| {
+                let agent = agent.clone();
+                let on_agent_action = on_agent_action.clone();
+                div()
+                    .id(action.label())
+                    .px(px(8.0))
+                    .py(px(4.0))
+                    .text_color(palette.text)
+                    .text_sm()
+                    .cursor_pointer()
+                    .hover(|div| div.bg(palette.element_bg))
+                    .child(action.label())
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        if let Some(on_agent_action) = &on_agent_action {
+                            on_agent_action(&agent, action, window, cx);
+                        }
+                    })
+            }))
+    }
+}
+
+pub(crate) struct ContextBar {
     fill: f32,
     palette: AgentSectionPalette,
 }
 
 impl ContextBar {
-    fn new(fill: f32, palette: AgentSectionPalette) -> Self {
-        Self { fill, palette }
+    pub(crate) fn new(fill: f32, palette: AgentSectionPalette) -> Self {
+        Self {
+            fill: fill.clamp(0.0, 1.0),
+            palette,
+        }
     }
 }
 
@@ -222,6 +607,74 @@ impl IntoElement for ContextBar {
     }
 }
 
+/// Minimum delta between an agent's two most recent `token_usage_history`
+/// samples before [`TokenSparkline`] paints the latest bar as a warning
+/// spike instead of the normal accent color.
+const DEFAULT_SPIKE_THRESHOLD: u64 = 5_000;
+
+/// Tiny inline bar chart of an agent's recent cumulative token totals,
+/// rendered next to the `↓/↑` counts in `AgentRow`. Renders nothing when
+/// fewer than two samples are available.
+struct TokenSparkline {
+    samples: Vec<u64>,
+    palette: AgentSectionPalette,
+    spike_threshold: u64,
+}
+
+impl TokenSparkline {
+    fn new(samples: Vec<u64>, palette: AgentSectionPalette) -> Self {
+        Self {
+            samples,
+            palette,
+            spike_threshold: DEFAULT_SPIKE_THRESHOLD,
+        }
+    }
+
+    fn spike_threshold(mut self, spike_threshold: u64) -> Self {
+        self.spike_threshold = spike_threshold;
+        self
+    }
+}
+
+impl IntoElement for TokenSparkline {
+    type Element = gpui::Div;
+
+    fn into_element(self) -> Self::Element {
+        const BAR_WIDTH: f32 = 3.0;
+        const BAR_GAP: f32 = 1.0;
+        const ROW_HEIGHT: f32 = 16.0;
+
+        if self.samples.len() < 2 {
+            return div();
+        }
+
+        let max = self.samples.iter().cloned().max().unwrap_or(1).max(1);
+        let last_index = self.samples.len() - 1;
+        let latest_delta =
+            self.samples[last_index].saturating_sub(self.samples[last_index - 1]);
+        let spiking = latest_delta > self.spike_threshold;
+
+        let mut bars = div().flex().items_end().gap(px(BAR_GAP));
+        for (index, &value) in self.samples.iter().enumerate() {
+            let height = (value as f32 / max as f32 * ROW_HEIGHT).max(1.0);
+            let color = if index == last_index && spiking {
+                self.palette.accent_warning
+            } else {
+                self.palette.accent_info
+            };
+            bars = bars.child(
+                div()
+                    .w(px(BAR_WIDTH))
+                    .h(px(height))
+                    .rounded(px(1.0))
+                    .bg(color),
+            );
+        }
+
+        div().h(px(ROW_HEIGHT)).flex().items_end().child(bars)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,14 +705,17 @@ mod tests {
                 token_usage: Some(TokenUsage {
                     input_tokens: 45230,
                     output_tokens: 12450,
+                    model: None,
                 }),
                 context_fill: Some(0.73),
+                token_usage_history: vec![],
             },
             AgentInfo {
                 name: "GreenForest".to_string(),
                 status: AgentStatus::Idle,
                 token_usage: None,
                 context_fill: None,
+                token_usage_history: vec![],
             },
         ];
 
@@ -0,0 +1,199 @@
+#![allow(dead_code)]
+
+//! Models a convoy as a task-runner operation, in the style of moon's
+//! executor/reporter: a small state machine with sub-steps, timing, and a
+//! content hash so repeated identical convoys can be reported as cached
+//! rather than re-run.
+
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dashboard_buffer::ConvoyInfo;
+
+/// Lifecycle state of a [`ConvoyOperation`] or one of its [`ConvoyStep`]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConvoyState {
+    Queued,
+    Running,
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// One sub-step of a convoy's operation, reported as it runs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConvoyStep {
+    pub name: String,
+    pub state: ConvoyState,
+}
+
+/// A convoy modeled as a task-runner operation rather than a bare
+/// percentage: a state machine with sub-steps, timing, and a content hash
+/// of its inputs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConvoyOperation {
+    pub state: ConvoyState,
+    pub steps: Vec<ConvoyStep>,
+    /// Hash of whatever inputs determine whether this convoy's work is
+    /// already done. Two runs with matching hashes can report `Skipped`
+    /// ("cached") instead of re-running - see [`Self::is_cached_against`].
+    pub content_hash: String,
+    /// Milliseconds since the operation started; updated on each refresh
+    /// while `state == Running`, frozen once it finishes.
+    pub elapsed_ms: u64,
+}
+
+impl ConvoyOperation {
+    /// Hashes `inputs` (e.g. a serialized task spec) into a stable content
+    /// hash suitable for [`Self::content_hash`].
+    pub fn hash_inputs(inputs: &str) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        inputs.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Whether this operation's inputs match `other`'s, meaning its work is
+    /// already done and it can be reported as cached/skipped rather than
+    /// re-run.
+    pub fn is_cached_against(&self, other: &ConvoyOperation) -> bool {
+        self.content_hash == other.content_hash
+    }
+}
+
+/// Aggregate counts produced by a [`Reporter`] across every convoy it saw.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConvoySummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub cached: usize,
+    pub total_elapsed_ms: u64,
+}
+
+/// Receives convoy lifecycle notifications, in the style of moon's task
+/// runner reporters, and produces a running [`ConvoySummary`].
+pub trait Reporter {
+    /// Records one convoy's current state. Convoys without an
+    /// [`ConvoyOperation`] (older data sources reporting a bare percentage)
+    /// are ignored.
+    fn on_convoy(&mut self, convoy: &ConvoyInfo);
+
+    /// Counts/timing accumulated across every `on_convoy` call so far.
+    fn summary(&self) -> ConvoySummary;
+}
+
+/// Default [`Reporter`] that renders each convoy's status into an in-memory
+/// line buffer - suitable for a console/log sink or for `DashboardFormatter`
+/// to embed inline under the dashboard's convoy section.
+#[derive(Default)]
+pub struct BufferReporter {
+    lines: Vec<String>,
+    summary: ConvoySummary,
+}
+
+impl BufferReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rendered status lines, one per convoy seen so far, oldest first.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    fn icon(state: ConvoyState) -> &'static str {
+        match state {
+            ConvoyState::Queued => "⏳",
+            ConvoyState::Running => "▶",
+            ConvoyState::Passed => "✔",
+            ConvoyState::Failed => "✗",
+            ConvoyState::Skipped => "⏭",
+        }
+    }
+}
+
+impl Reporter for BufferReporter {
+    fn on_convoy(&mut self, convoy: &ConvoyInfo) {
+        let Some(op) = &convoy.operation else {
+            return;
+        };
+
+        match op.state {
+            ConvoyState::Passed => self.summary.passed += 1,
+            ConvoyState::Failed => self.summary.failed += 1,
+            ConvoyState::Skipped => self.summary.cached += 1,
+            ConvoyState::Queued | ConvoyState::Running => {}
+        }
+        self.summary.total_elapsed_ms += op.elapsed_ms;
+
+        self.lines.push(format!(
+            "{} {} ({:?}, {}ms)",
+            Self::icon(op.state),
+            convoy.id,
+            op.state,
+            op.elapsed_ms
+        ));
+    }
+
+    fn summary(&self) -> ConvoySummary {
+        self.summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convoy(state: ConvoyState, elapsed_ms: u64) -> ConvoyInfo {
+        ConvoyInfo {
+            id: "convoy-1".into(),
+            progress: 1.0,
+            operation: Some(ConvoyOperation {
+                state,
+                steps: Vec::new(),
+                content_hash: "abc".into(),
+                elapsed_ms,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_hash_inputs_is_stable() {
+        assert_eq!(
+            ConvoyOperation::hash_inputs("same input"),
+            ConvoyOperation::hash_inputs("same input")
+        );
+        assert_ne!(
+            ConvoyOperation::hash_inputs("input a"),
+            ConvoyOperation::hash_inputs("input b")
+        );
+    }
+
+    #[test]
+    fn test_buffer_reporter_accumulates_summary() {
+        let mut reporter = BufferReporter::new();
+        reporter.on_convoy(&convoy(ConvoyState::Passed, 100));
+        reporter.on_convoy(&convoy(ConvoyState::Failed, 50));
+        reporter.on_convoy(&convoy(ConvoyState::Skipped, 0));
+
+        let summary = reporter.summary();
+        assert_eq!(summary.passed, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.cached, 1);
+        assert_eq!(summary.total_elapsed_ms, 150);
+        assert_eq!(reporter.lines().len(), 3);
+    }
+
+    #[test]
+    fn test_buffer_reporter_ignores_convoys_without_operation() {
+        let mut reporter = BufferReporter::new();
+        reporter.on_convoy(&ConvoyInfo {
+            id: "bare".into(),
+            progress: 0.5,
+            operation: None,
+        });
+        assert!(reporter.lines().is_empty());
+        assert_eq!(reporter.summary(), ConvoySummary::default());
+    }
+}
@@ -187,10 +187,12 @@ mod tests {
             ConvoyInfo {
                 id: "refactor-auth".to_string(),
                 progress: 0.65,
+                operation: None,
             },
             ConvoyInfo {
                 id: "migrate-db".to_string(),
                 progress: 0.30,
+                operation: None,
             },
         ];
 
@@ -211,14 +213,17 @@ mod tests {
             ConvoyInfo {
                 id: "full-progress".to_string(),
                 progress: 1.0,
+                operation: None,
             },
             ConvoyInfo {
                 id: "zero-progress".to_string(),
                 progress: 0.0,
+                operation: None,
             },
             ConvoyInfo {
                 id: "half-progress".to_string(),
                 progress: 0.5,
+                operation: None,
             },
         ];
 
@@ -2,13 +2,27 @@
 
 use gpui::{
     AnyElement, App, Context, EventEmitter, FocusHandle, Focusable, Hsla, IntoElement,
-    ParentElement, Render, Styled, Window, div, px, rgb,
+    ParentElement, Render, Styled, Task, Timer, Window, div, px, rgb,
 };
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use crate::agent_section::{AgentSection, AgentSectionPalette};
+use crate::convoy_operation::{BufferReporter, ConvoyOperation, ConvoyState, Reporter};
 use crate::convoy_section::{ConvoySection, ConvoySectionPalette};
+use crate::history::SampleHistory;
+use crate::history_store::{HistoryMetric, HistorySample, HistoryStore};
+use crate::notifications::NotificationSink;
 use crate::rig_section::{RigSection, RigSectionPalette};
+use std::collections::HashMap;
+
+/// Number of samples retained per agent for context-fill trend sparklines.
+const CONTEXT_FILL_HISTORY_CAPACITY: usize = 60;
+
+/// Number of samples retained per convoy for progress trend sparklines.
+const CONVOY_PROGRESS_HISTORY_CAPACITY: usize = 60;
 
 /// Dashboard color palette matching Zed's One Dark theme.
 /// Values from: assets/themes/one/one.json
@@ -45,20 +59,6 @@ impl DashboardPalette {
         }
     }
 
-    fn to_agent_section_palette(&self) -> AgentSectionPalette {
-        AgentSectionPalette {
-            panel_bg: self.panel_bg,
-            border_variant: self.border_variant,
-            text: self.text,
-            text_muted: self.text_muted,
-            accent_success: self.accent_success,
-            accent_warning: self.accent_warning,
-            accent_error: self.accent_error,
-            accent_info: self.accent_info,
-            element_bg: self.element_bg,
-        }
-    }
-
     fn to_convoy_section_palette(&self) -> ConvoySectionPalette {
         ConvoySectionPalette {
             panel_bg: self.panel_bg,
@@ -94,30 +94,58 @@ pub enum DashboardEvent {
     AgentRemoved(String),
     /// An agent's status changed
     AgentStatusChanged { name: String, status: AgentStatus },
+    /// A convoy's progress crossed 1.0
+    ConvoyCompleted(String),
 }
 
 /// Dashboard data returned by any data source
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DashboardData {
     pub agents: Vec<AgentInfo>,
     pub convoys: Vec<ConvoyInfo>,
     pub rigs: Vec<RigInfo>,
 }
 
-#[derive(Clone, Debug)]
+impl DashboardData {
+    /// Parses a `DashboardData` snapshot from its JSON wire format.
+    pub fn from_json(json: &str) -> Result<Self, DashboardError> {
+        serde_json::from_str(json).map_err(|e| DashboardError::ParseError(e.to_string()))
+    }
+
+    /// Serializes this snapshot to the same JSON wire format used by
+    /// [`Self::from_json`], the HTTP data source, and the mock source's fixtures.
+    pub fn to_json(&self) -> Result<String, DashboardError> {
+        serde_json::to_string(self).map_err(|e| DashboardError::ParseError(e.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AgentInfo {
     pub name: String,
     pub status: AgentStatus,
     pub token_usage: Option<TokenUsage>,
     pub context_fill: Option<f32>,
+    /// Recent cumulative `input_tokens + output_tokens` samples, oldest
+    /// first, used to render a trend sparkline next to the current count.
+    #[serde(default)]
+    pub token_usage_history: Vec<u64>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct TokenUsage {
     pub input_tokens: u64,
     pub output_tokens: u64,
+    /// Model id used for this agent's turn, e.g. `"gpt-4o"`, matching the ids
+    /// in [`crate::tokenizer::model_pricing`]; `None` for data sources that
+    /// don't report it. The dashboard doesn't read this field: `context_fill`
+    /// is whatever the data source supplied.
+    #[serde(default)]
+    pub model: Option<String>,
 }
 
+/// Agent status. Serializes to a stable `{"status": "...", "message": "..."}`
+/// shape rather than serde's default untagged-tuple encoding, so the wire
+/// format doesn't change if variants are reordered.
 #[derive(Clone, Debug, PartialEq)]
 pub enum AgentStatus {
     Active,
@@ -125,13 +153,55 @@ pub enum AgentStatus {
     Error(String),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum AgentStatusWire {
+    Active,
+    Idle,
+    Error { message: String },
+}
+
+impl Serialize for AgentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let wire = match self {
+            AgentStatus::Active => AgentStatusWire::Active,
+            AgentStatus::Idle => AgentStatusWire::Idle,
+            AgentStatus::Error(message) => AgentStatusWire::Error {
+                message: message.clone(),
+            },
+        };
+        wire.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AgentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match AgentStatusWire::deserialize(deserializer)? {
+            AgentStatusWire::Active => AgentStatus::Active,
+            AgentStatusWire::Idle => AgentStatus::Idle,
+            AgentStatusWire::Error { message } => AgentStatus::Error(message),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConvoyInfo {
     pub id: String,
     pub progress: f32,
+    /// Task-runner operation detail (state, steps, timing), if the data
+    /// source reports it. `None` falls back to rendering just the
+    /// percentage bar, for data sources that only know the raw progress.
+    #[serde(default)]
+    pub operation: Option<ConvoyOperation>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RigInfo {
     pub name: String,
     pub path: String,
@@ -141,6 +211,19 @@ pub struct RigInfo {
 pub trait DashboardDataSource: Send + Sync {
     fn fetch(&self) -> Result<DashboardData, DashboardError>;
     fn is_available(&self) -> bool;
+
+    /// Async variant of [`Self::fetch`] used by the background polling loop.
+    ///
+    /// Sources that can only fetch synchronously (subprocess I/O, blocking
+    /// HTTP clients) can rely on this default, which just calls [`Self::fetch`]
+    /// directly; slower sources should override it to avoid blocking whatever
+    /// executor drives the returned future.
+    fn fetch_async<'a>(
+        &'a self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<DashboardData, DashboardError>> + Send + 'a>>
+    {
+        Box::pin(async move { self.fetch() })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -160,11 +243,24 @@ impl std::fmt::Display for DashboardError {
     }
 }
 
+impl std::error::Error for DashboardError {}
+
 /// Formats dashboard data for display
 pub struct DashboardFormatter;
 
 impl DashboardFormatter {
     pub fn format(data: &DashboardData) -> String {
+        Self::format_with_history(data, &HashMap::new(), &HashMap::new())
+    }
+
+    /// Same as [`Self::format`], but also renders a trailing sparkline and
+    /// trend arrow next to each agent's context fill and each convoy's
+    /// progress when history is available.
+    pub fn format_with_history(
+        data: &DashboardData,
+        context_fill_history: &HashMap<String, SampleHistory>,
+        convoy_progress_history: &HashMap<String, SampleHistory>,
+    ) -> String {
         let mut output = String::new();
 
         output.push_str("═══ Gastown Dashboard ═══\n\n");
@@ -191,6 +287,14 @@ impl DashboardFormatter {
                         tokens.input_tokens, tokens.output_tokens
                     ));
                 }
+                if let Some(history) = context_fill_history.get(&agent.name) {
+                    if !history.is_empty() {
+                        line.push_str(&format!(" {}", history.sparkline()));
+                        if let Some(arrow) = history.delta_arrow() {
+                            line.push_str(&format!(" {}", arrow));
+                        }
+                    }
+                }
                 output.push_str(&line);
                 output.push('\n');
             }
@@ -202,15 +306,44 @@ impl DashboardFormatter {
         if data.convoys.is_empty() {
             output.push_str("  No active convoys\n");
         } else {
+            let mut reporter = BufferReporter::new();
             for convoy in &data.convoys {
+                reporter.on_convoy(convoy);
+
                 let progress_bar = Self::progress_bar(convoy.progress);
-                output.push_str(&format!(
-                    "  {} {} ({:.0}%)\n",
+                let mut line = format!(
+                    "  {} {} ({:.0}%)",
                     convoy.id,
                     progress_bar,
                     convoy.progress * 100.0
-                ));
+                );
+                if let Some(history) = convoy_progress_history.get(&convoy.id) {
+                    if !history.is_empty() {
+                        line.push_str(&format!(" {}", history.sparkline()));
+                        if let Some(arrow) = history.delta_arrow() {
+                            line.push_str(&format!(" {}", arrow));
+                        }
+                    }
+                }
+                output.push_str(&line);
+                output.push('\n');
+
+                // While a convoy is running, show its sub-step detail
+                // instead of leaving the reader with only a percentage.
+                if let Some(op) = &convoy.operation {
+                    if op.state == ConvoyState::Running {
+                        for step in &op.steps {
+                            output.push_str(&format!("      └─ {} [{:?}]\n", step.name, step.state));
+                        }
+                    }
+                }
             }
+
+            let summary = reporter.summary();
+            output.push_str(&format!(
+                "  ── {} passed, {} failed, {} cached ({}ms total) ──\n",
+                summary.passed, summary.failed, summary.cached, summary.total_elapsed_ms
+            ));
         }
         output.push('\n');
 
@@ -257,6 +390,22 @@ pub struct DashboardView {
     data_source: Arc<dyn DashboardDataSource>,
     last_update: Option<std::time::Instant>,
     connection_status: ConnectionStatus,
+    /// Handle to the background auto-refresh loop, if one is running.
+    /// Dropping it (or setting `refresh_interval` to `None`) cancels the poll.
+    refresh_task: Option<Task<()>>,
+    /// Monotonically increasing id, one per refresh attempt, used to correlate
+    /// the `tracing` span for a fetch with its resulting log events.
+    refresh_id: AtomicU64,
+    /// Sinks notified of selected `DashboardEvent`s (e.g. outbound webhooks).
+    notification_sinks: Vec<Arc<dyn NotificationSink>>,
+    /// Context-fill trend per agent name, appended to on every successful
+    /// refresh and evicted when an agent disappears.
+    context_fill_history: HashMap<String, SampleHistory>,
+    /// Progress trend per convoy id, mirroring `context_fill_history`.
+    convoy_progress_history: HashMap<String, SampleHistory>,
+    /// Durable store of agent/convoy samples surviving across restarts.
+    /// `None` until [`Self::set_history_store`] is called.
+    history_store: Option<Arc<HistoryStore>>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -275,14 +424,117 @@ impl DashboardView {
             data_source,
             last_update: None,
             connection_status: ConnectionStatus::Unknown,
+            refresh_task: None,
+            refresh_id: AtomicU64::new(0),
+            notification_sinks: Vec::new(),
+            context_fill_history: HashMap::new(),
+            convoy_progress_history: HashMap::new(),
+            history_store: None,
         };
         view.refresh_sync();
         view
     }
 
+    /// Registers a sink to be notified of dashboard events going forward.
+    pub fn add_notification_sink(&mut self, sink: Arc<dyn NotificationSink>) {
+        self.notification_sinks.push(sink);
+    }
+
+    /// Forwards `event` to every registered sink on gpui's background
+    /// executor, so a slow or unreachable webhook can never stall a refresh.
+    fn forward_to_sinks(&self, event: &DashboardEvent, cx: &mut Context<Self>) {
+        let sinks = self.notification_sinks.clone();
+        let event = event.clone();
+        cx.background_spawn(async move {
+            crate::notifications::forward(&sinks, &event);
+        })
+        .detach();
+    }
+
+    /// Attaches a durable history store; agent/convoy samples are appended
+    /// to it on every successful refresh from then on.
+    pub fn set_history_store(&mut self, store: Arc<HistoryStore>) {
+        self.history_store = Some(store);
+    }
+
+    /// Returns `metric`/`name` samples recorded within the last `window`,
+    /// or an empty list if no history store is attached or the query fails.
+    pub fn history(&self, metric: HistoryMetric, name: &str, window: Duration) -> Vec<HistorySample> {
+        let Some(store) = &self.history_store else {
+            return Vec::new();
+        };
+        match store.history(metric, name, window) {
+            Ok(samples) => samples,
+            Err(err) => {
+                tracing::warn!(%name, error = %err, "history query failed");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Starts (or replaces) a background poll that calls the data source every
+    /// `interval`, updating `data`/`error`/`last_update` and emitting
+    /// [`DashboardEvent::DataRefreshed`] on each successful tick whose data
+    /// differs from what's displayed (see [`Self::apply_refresh`]) and
+    /// [`DashboardEvent::ConnectionChanged`] whenever `is_available()` flips.
+    ///
+    /// The fetch itself runs on gpui's background executor rather than the
+    /// task driving this loop, so a slow or blocking `DashboardDataSource`
+    /// (subprocess I/O, a synchronous HTTP client) never freezes the window.
+    ///
+    /// Passing `None` cancels any running poll without starting a new one.
+    pub fn set_refresh_interval(&mut self, interval: Option<Duration>, cx: &mut Context<Self>) {
+        self.refresh_task = interval.map(|interval| {
+            cx.spawn(async move |this, cx| {
+                // Seed from the status the last refresh recorded, so the first
+                // tick only emits `ConnectionChanged` on an actual flip.
+                let mut was_available: Option<bool> = this
+                    .read_with(cx, |view, _| match view.connection_status {
+                        ConnectionStatus::Connected => Some(true),
+                        ConnectionStatus::Disconnected => Some(false),
+                        ConnectionStatus::Unknown => None,
+                    })
+                    .ok()
+                    .flatten();
+                loop {
+                    Timer::after(interval).await;
+
+                    let Ok(data_source) = this.read_with(cx, |view, _| view.data_source.clone())
+                    else {
+                        return;
+                    };
+
+                    let (available, fetch_result) = cx
+                        .background_spawn(async move {
+                            let available = data_source.is_available();
+                            let fetch_result = data_source.fetch_async().await;
+                            (available, fetch_result)
+                        })
+                        .await;
+
+                    let Ok(()) = this.update(cx, |view, cx| {
+                        view.apply_refresh(fetch_result, cx);
+                        if was_available != Some(available) {
+                            was_available = Some(available);
+                            let event = DashboardEvent::ConnectionChanged(view.connection_status.clone());
+                            view.forward_to_sinks(&event, cx);
+                            cx.emit(event);
+                        }
+                    }) else {
+                        return;
+                    };
+                }
+            })
+        });
+    }
+
     pub fn content(&self) -> String {
         match (&self.data, &self.error) {
-            (Some(data), _) => DashboardFormatter::format(data),
+            (Some(data), _) => DashboardFormatter::format_with_history(
+                data,
+                &self.context_fill_history,
+                &self.convoy_progress_history,
+            ),
             (_, Some(err)) => DashboardFormatter::format_error(err),
             _ => "Loading...".into(),
         }
@@ -301,12 +553,25 @@ impl DashboardView {
     }
 
     pub fn refresh(&mut self, cx: &mut Context<Self>) {
-        self.refresh_sync();
+        let transitions = self.refresh_sync();
+        self.forward_to_sinks(&DashboardEvent::DataRefreshed, cx);
         cx.emit(DashboardEvent::DataRefreshed);
+        for event in transitions {
+            self.forward_to_sinks(&event, cx);
+            cx.emit(event);
+        }
         cx.notify();
     }
 
-    fn refresh_sync(&mut self) {
+    /// Refreshes synchronously and returns the "meaningful" transitions
+    /// (see [`Self::detect_transitions`]) observed since the previous
+    /// snapshot, for the caller to emit/forward once it has a `Context`.
+    fn refresh_sync(&mut self) -> Vec<DashboardEvent> {
+        let refresh_id = self.refresh_id.fetch_add(1, Ordering::SeqCst);
+        let span = tracing::info_span!("dashboard_refresh", refresh_id);
+        let _enter = span.enter();
+        let started = std::time::Instant::now();
+
         self.connection_status = if self.data_source.is_available() {
             ConnectionStatus::Connected
         } else {
@@ -315,18 +580,204 @@ impl DashboardView {
 
         match self.data_source.fetch() {
             Ok(data) => {
+                tracing::info!(
+                    agents = data.agents.len(),
+                    convoys = data.convoys.len(),
+                    rigs = data.rigs.len(),
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    connection_status = ?self.connection_status,
+                    "dashboard refresh succeeded"
+                );
+                let transitions = Self::detect_transitions(self.data.as_ref(), &data);
+                self.record_context_fill_history(&data);
+                self.record_convoy_progress_history(&data);
+                self.persist_history(&data);
                 self.data = Some(data);
                 self.error = None;
                 self.last_update = Some(std::time::Instant::now());
+                transitions
             }
             Err(err) => {
-                self.data = None;
+                tracing::warn!(
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    connection_status = ?self.connection_status,
+                    error = %err,
+                    "dashboard refresh failed"
+                );
+                // Keep the last successful snapshot on screen; a transient
+                // failure only flips `connection_status`, it doesn't blank
+                // out the dashboard.
                 self.error = Some(err);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Diffs `current` against `previous` (the snapshot before this
+    /// refresh) for "meaningful" agent/convoy transitions worth notifying
+    /// about: an agent entering [`AgentStatus::Error`], an agent recovering
+    /// from error to [`AgentStatus::Active`], and a convoy's `progress`
+    /// crossing `1.0`. Ordinary status churn (e.g. idle <-> active) and new
+    /// or removed agents/convoys are not considered meaningful here.
+    fn detect_transitions(
+        previous: Option<&DashboardData>,
+        current: &DashboardData,
+    ) -> Vec<DashboardEvent> {
+        let Some(previous) = previous else {
+            return Vec::new();
+        };
+
+        let mut events = Vec::new();
+
+        for agent in &current.agents {
+            let Some(prior) = previous.agents.iter().find(|a| a.name == agent.name) else {
+                continue;
+            };
+
+            let entered_error = matches!(agent.status, AgentStatus::Error(_))
+                && !matches!(prior.status, AgentStatus::Error(_));
+            let recovered = matches!(prior.status, AgentStatus::Error(_))
+                && matches!(agent.status, AgentStatus::Active);
+
+            if entered_error || recovered {
+                events.push(DashboardEvent::AgentStatusChanged {
+                    name: agent.name.clone(),
+                    status: agent.status.clone(),
+                });
+            }
+        }
+
+        for convoy in &current.convoys {
+            let Some(prior) = previous.convoys.iter().find(|c| c.id == convoy.id) else {
+                continue;
+            };
+
+            if convoy.progress >= 1.0 && prior.progress < 1.0 {
+                events.push(DashboardEvent::ConvoyCompleted(convoy.id.clone()));
+            }
+        }
+
+        events
+    }
+
+    /// Appends this refresh's context-fill samples to each agent's history
+    /// and evicts entries for agents that are no longer present.
+    fn record_context_fill_history(&mut self, data: &DashboardData) {
+        let present: std::collections::HashSet<&str> =
+            data.agents.iter().map(|a| a.name.as_str()).collect();
+        self.context_fill_history
+            .retain(|name, _| present.contains(name.as_str()));
+
+        for agent in &data.agents {
+            if let Some(fill) = agent.context_fill {
+                self.context_fill_history
+                    .entry(agent.name.clone())
+                    .or_insert_with(|| SampleHistory::new(CONTEXT_FILL_HISTORY_CAPACITY))
+                    .push(fill);
+            }
+        }
+    }
+
+    /// Appends this refresh's progress samples to each convoy's history and
+    /// evicts entries for convoys that are no longer present.
+    fn record_convoy_progress_history(&mut self, data: &DashboardData) {
+        let present: std::collections::HashSet<&str> =
+            data.convoys.iter().map(|c| c.id.as_str()).collect();
+        self.convoy_progress_history
+            .retain(|id, _| present.contains(id.as_str()));
+
+        for convoy in &data.convoys {
+            self.convoy_progress_history
+                .entry(convoy.id.clone())
+                .or_insert_with(|| SampleHistory::new(CONVOY_PROGRESS_HISTORY_CAPACITY))
+                .push(convoy.progress);
+        }
+    }
+
+    /// Persists this refresh's agent/convoy metrics to the durable history
+    /// store, if one is attached. Failures are logged and otherwise
+    /// ignored - a broken history DB should never block the live dashboard.
+    fn persist_history(&self, data: &DashboardData) {
+        let Some(store) = &self.history_store else {
+            return;
+        };
+
+        for agent in &data.agents {
+            if let Some(fill) = agent.context_fill {
+                if let Err(err) = store.record(HistoryMetric::AgentContextFill, &agent.name, fill)
+                {
+                    tracing::warn!(agent = %agent.name, error = %err, "failed to persist context_fill history");
+                }
+            }
+            if let Some(tokens) = &agent.token_usage {
+                let total = (tokens.input_tokens + tokens.output_tokens) as f32;
+                if let Err(err) = store.record(HistoryMetric::AgentTokenUsage, &agent.name, total)
+                {
+                    tracing::warn!(agent = %agent.name, error = %err, "failed to persist token_usage history");
+                }
+            }
+        }
+
+        for convoy in &data.convoys {
+            if let Err(err) =
+                store.record(HistoryMetric::ConvoyProgress, &convoy.id, convoy.progress)
+            {
+                tracing::warn!(convoy = %convoy.id, error = %err, "failed to persist convoy progress history");
+            }
+        }
+    }
+
+    /// Applies the result of an async fetch performed by the background poll,
+    /// then emits `DataRefreshed` and repaints on success.
+    ///
+    /// Skips the repaint (and the `DataRefreshed` event) when the fetched
+    /// data is identical to what's already displayed, so an idle dashboard
+    /// polling a quiet `gt` instance doesn't reformat and redraw every tick.
+    fn apply_refresh(&mut self, result: Result<DashboardData, DashboardError>, cx: &mut Context<Self>) {
+        self.connection_status = if self.data_source.is_available() {
+            ConnectionStatus::Connected
+        } else {
+            ConnectionStatus::Disconnected
+        };
+
+        match result {
+            Ok(data) => {
+                let transitions = Self::detect_transitions(self.data.as_ref(), &data);
+                self.record_context_fill_history(&data);
+                self.record_convoy_progress_history(&data);
+                self.persist_history(&data);
+                self.error = None;
+                self.last_update = Some(std::time::Instant::now());
+
+                if self.data.as_ref() != Some(&data) {
+                    self.data = Some(data);
+                    cx.emit(DashboardEvent::DataRefreshed);
+                    self.forward_to_sinks(&DashboardEvent::DataRefreshed, cx);
+                    cx.notify();
+                }
+                for event in transitions {
+                    self.forward_to_sinks(&event, cx);
+                    cx.emit(event);
+                }
+            }
+            Err(err) => {
+                // Keep the last successful snapshot cached; a transient
+                // failure only flips `connection_status`, it doesn't blank
+                // out the dashboard.
+                self.error = Some(err);
+                cx.notify();
             }
         }
     }
 }
 
+impl Drop for DashboardView {
+    fn drop(&mut self) {
+        // Dropping `refresh_task` cancels the spawned poll loop.
+        self.refresh_task.take();
+    }
+}
+
 impl Focusable for DashboardView {
     fn focus_handle(&self, _cx: &App) -> FocusHandle {
         self.focus_handle.clone()
@@ -336,11 +787,11 @@ impl Focusable for DashboardView {
 impl EventEmitter<DashboardEvent> for DashboardView {}
 
 impl Render for DashboardView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let palette = DashboardPalette::one_dark();
 
         let content: AnyElement = if let Some(ref data) = self.data {
-            self.render_data(data, &palette).into_any_element()
+            self.render_data(data, &palette, cx).into_any_element()
         } else if let Some(ref err) = self.error {
             self.render_error(err, &palette).into_any_element()
         } else {
@@ -358,7 +809,12 @@ impl Render for DashboardView {
 }
 
 impl DashboardView {
-    fn render_data(&self, data: &DashboardData, palette: &DashboardPalette) -> impl IntoElement {
+    fn render_data(
+        &self,
+        data: &DashboardData,
+        palette: &DashboardPalette,
+        cx: &App,
+    ) -> impl IntoElement {
         div()
             .flex()
             .flex_col()
@@ -366,7 +822,7 @@ impl DashboardView {
             .p(px(16.0))
             .gap(px(16.0))
             .child(self.render_header(palette))
-            .child(self.render_agents_section(&data.agents, palette))
+            .child(self.render_agents_section(&data.agents, cx))
             .child(self.render_convoys_section(&data.convoys, palette))
             .child(self.render_rigs_section(&data.rigs, palette))
     }
@@ -435,12 +891,8 @@ impl DashboardView {
         div().text_sm().text_color(color).child(label)
     }
 
-    fn render_agents_section(
-        &self,
-        agents: &[AgentInfo],
-        palette: &DashboardPalette,
-    ) -> impl IntoElement {
-        AgentSection::new(agents, palette.to_agent_section_palette())
+    fn render_agents_section(&self, agents: &[AgentInfo], cx: &App) -> impl IntoElement {
+        AgentSection::new(agents, AgentSectionPalette::from_theme(cx))
     }
 
     fn render_convoys_section(
@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+//! Lightweight fuzzy string matching, fzf-style: every character of `query`
+//! must appear in order within `candidate`, with bonuses for consecutive
+//! runs and matches at the start of a word. Used to rank and highlight
+//! `AgentRow`s against a typed filter query.
+
+/// A successful match of `query` against a candidate string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzyMatch {
+    /// Higher is a better match.
+    pub score: i64,
+    /// Character positions (indices into `candidate.chars()`, not byte
+    /// offsets) that matched a query character, in order, for highlighting.
+    pub matched_indices: Vec<usize>,
+}
+
+/// Matches `query` against `candidate` case-insensitively. Returns `None`
+/// if any query character isn't found as an in-order subsequence.
+pub fn fuzzy_match(candidate: &str, query: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            matched_indices: Vec::new(),
+        });
+    }
+
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let mut query_chars = query.chars().map(|c| c.to_ascii_lowercase());
+
+    let mut matched_indices = Vec::new();
+    let mut score: i64 = 0;
+    let mut last_match: Option<usize> = None;
+    let mut current = query_chars.next()?;
+
+    for (index, &ch) in candidate_chars.iter().enumerate() {
+        if ch.to_ascii_lowercase() != current {
+            continue;
+        }
+
+        score += 1;
+        if let Some(last) = last_match {
+            if index == last + 1 {
+                score += 5; // consecutive-match bonus
+            }
+        }
+        if index == 0 || !candidate_chars[index - 1].is_alphanumeric() {
+            score += 3; // word-boundary bonus
+        }
+
+        matched_indices.push(index);
+        last_match = Some(index);
+
+        current = match query_chars.next() {
+            Some(next) => next,
+            None => {
+                return Some(FuzzyMatch {
+                    score,
+                    matched_indices,
+                });
+            }
+        };
+    }
+
+    None
+}
+
+/// Matches every candidate against `query`, keeping only matches and
+/// sorting by descending score (ties broken by original order).
+pub fn rank<'a, T>(
+    candidates: impl IntoIterator<Item = &'a T>,
+    query: &str,
+    candidate_text: impl Fn(&'a T) -> &'a str,
+) -> Vec<(&'a T, FuzzyMatch)> {
+    let mut ranked: Vec<(&'a T, FuzzyMatch)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            fuzzy_match(candidate_text(candidate), query).map(|m| (candidate, m))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.score.cmp(&a.1.score));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let m = fuzzy_match("BlueLake", "").unwrap();
+        assert_eq!(m.matched_indices.len(), 0);
+    }
+
+    #[test]
+    fn test_matches_subsequence_case_insensitively() {
+        let m = fuzzy_match("BlueLake", "blk").unwrap();
+        assert_eq!(m.matched_indices, vec![0, 1, 6]);
+    }
+
+    #[test]
+    fn test_rejects_out_of_order_or_missing_chars() {
+        assert!(fuzzy_match("BlueLake", "kbl").is_none());
+        assert!(fuzzy_match("BlueLake", "xyz").is_none());
+    }
+
+    #[test]
+    fn test_consecutive_match_scores_higher_than_scattered() {
+        let consecutive = fuzzy_match("BlueLake", "Blue").unwrap();
+        let scattered = fuzzy_match("BlueLake", "Bake").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn test_rank_filters_and_sorts_by_score() {
+        let names = vec![
+            "BlueLake".to_string(),
+            "GreenCastle".to_string(),
+            "BlackLake".to_string(),
+        ];
+        let ranked = rank(&names, "lake", |s: &String| s.as_str());
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].0, "BlueLake");
+    }
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+
+/// Block glyphs used to quantize a sample window into a compact sparkline,
+/// from lowest to highest.
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A bounded ring buffer of `f32` samples (e.g. context fill, token counts)
+/// for a single agent or convoy, used to render trend sparklines.
+#[derive(Clone, Debug)]
+pub struct SampleHistory {
+    capacity: usize,
+    samples: VecDeque<f32>,
+}
+
+impl SampleHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, value: f32) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Renders the sample window as a compact block-glyph sparkline,
+    /// quantized to the observed min/max in this window.
+    pub fn sparkline(&self) -> String {
+        if self.samples.is_empty() {
+            return String::new();
+        }
+
+        let min = self.samples.iter().cloned().fold(f32::INFINITY, f32::min);
+        let max = self
+            .samples
+            .iter()
+            .cloned()
+            .fold(f32::NEG_INFINITY, f32::max);
+        let range = (max - min).max(f32::EPSILON);
+
+        self.samples
+            .iter()
+            .map(|&v| {
+                let normalized = ((v - min) / range).clamp(0.0, 1.0);
+                let index = (normalized * (BLOCKS.len() - 1) as f32).round() as usize;
+                BLOCKS[index]
+            })
+            .collect()
+    }
+
+    /// Returns `↑`/`↓` comparing the newest sample to the oldest, or `None`
+    /// when fewer than two samples have been collected.
+    pub fn delta_arrow(&self) -> Option<&'static str> {
+        let first = *self.samples.front()?;
+        let last = *self.samples.back()?;
+        if self.samples.len() < 2 {
+            return None;
+        }
+        Some(if last >= first { "↑" } else { "↓" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_oldest_sample_past_capacity() {
+        let mut history = SampleHistory::new(3);
+        history.push(1.0);
+        history.push(2.0);
+        history.push(3.0);
+        history.push(4.0);
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn test_sparkline_empty_when_no_samples() {
+        let history = SampleHistory::new(8);
+        assert_eq!(history.sparkline(), "");
+        assert_eq!(history.delta_arrow(), None);
+    }
+
+    #[test]
+    fn test_delta_arrow_reflects_trend() {
+        let mut rising = SampleHistory::new(8);
+        rising.push(0.1);
+        rising.push(0.5);
+        assert_eq!(rising.delta_arrow(), Some("↑"));
+
+        let mut falling = SampleHistory::new(8);
+        falling.push(0.8);
+        falling.push(0.2);
+        assert_eq!(falling.delta_arrow(), Some("↓"));
+    }
+}
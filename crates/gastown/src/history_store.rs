@@ -0,0 +1,214 @@
+#![allow(dead_code)]
+
+//! Durable trend history for dashboard metrics.
+//!
+//! A thin wrapper around a single SQLite connection, in the style of Zed's
+//! internal `sqlez` crate: one table, plain SQL, and an idempotent migration
+//! run once at open time so a missing or empty database file just works.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusqlite::{Connection, params};
+
+use crate::dashboard_buffer::DashboardError;
+
+/// A trend metric persisted to the history store. Each variant is stored
+/// under its own `metric` string so [`HistoryStore::history`] can query one
+/// series without scanning the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HistoryMetric {
+    /// An agent's `context_fill`, keyed by agent name.
+    AgentContextFill,
+    /// An agent's total token usage (input + output), keyed by agent name.
+    AgentTokenUsage,
+    /// A convoy's `progress`, keyed by convoy id.
+    ConvoyProgress,
+}
+
+impl HistoryMetric {
+    fn as_str(self) -> &'static str {
+        match self {
+            HistoryMetric::AgentContextFill => "agent_context_fill",
+            HistoryMetric::AgentTokenUsage => "agent_token_usage",
+            HistoryMetric::ConvoyProgress => "convoy_progress",
+        }
+    }
+}
+
+/// A single timestamped sample returned by [`HistoryStore::history`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HistorySample {
+    pub recorded_at: SystemTime,
+    pub value: f32,
+}
+
+/// SQLite-backed store of timestamped per-agent/convoy samples, surviving
+/// across restarts of the dashboard.
+///
+/// Samples are append-only; `history` never prunes old rows, it's the
+/// caller's job to bound what it asks for via the `window` argument.
+pub struct HistoryStore {
+    conn: Mutex<Connection>,
+}
+
+impl HistoryStore {
+    /// Opens (creating if necessary) the store at [`Self::default_path`].
+    pub fn open_default() -> Result<Self, DashboardError> {
+        Self::open(Self::default_path())
+    }
+
+    /// Path to the default dashboard history database, `~/gt/dashboard.db`.
+    pub fn default_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_default();
+        PathBuf::from(home).join("gt").join("dashboard.db")
+    }
+
+    /// Opens (creating the file and its parent directory if necessary) the
+    /// store at `path`, running migrations against whatever's there -
+    /// including a brand-new, empty file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DashboardError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+        }
+
+        let conn =
+            Connection::open(path).map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+        Self::migrate(&conn)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Creates the `history_samples` table and its lookup index if they
+    /// don't already exist.
+    fn migrate(conn: &Connection) -> Result<(), DashboardError> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS history_samples (
+                metric      TEXT    NOT NULL,
+                name        TEXT    NOT NULL,
+                recorded_at INTEGER NOT NULL,
+                value       REAL    NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS history_samples_lookup
+                ON history_samples (metric, name, recorded_at);",
+        )
+        .map_err(|e| DashboardError::FetchFailed(e.to_string()))
+    }
+
+    /// Appends one sample for `metric`/`name`, timestamped now.
+    pub fn record(
+        &self,
+        metric: HistoryMetric,
+        name: &str,
+        value: f32,
+    ) -> Result<(), DashboardError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO history_samples (metric, name, recorded_at, value) VALUES (?1, ?2, ?3, ?4)",
+            params![metric.as_str(), name, now_unix_millis(), value as f64],
+        )
+        .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Returns every sample for `metric`/`name` recorded within the last
+    /// `window`, oldest first.
+    pub fn history(
+        &self,
+        metric: HistoryMetric,
+        name: &str,
+        window: Duration,
+    ) -> Result<Vec<HistorySample>, DashboardError> {
+        let since = now_unix_millis() - window.as_millis() as i64;
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare(
+                "SELECT recorded_at, value FROM history_samples
+                 WHERE metric = ?1 AND name = ?2 AND recorded_at >= ?3
+                 ORDER BY recorded_at ASC",
+            )
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![metric.as_str(), name, since], |row| {
+                let recorded_at: i64 = row.get(0)?;
+                let value: f64 = row.get(1)?;
+                Ok(HistorySample {
+                    recorded_at: UNIX_EPOCH + Duration::from_millis(recorded_at.max(0) as u64),
+                    value: value as f32,
+                })
+            })
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))
+    }
+}
+
+fn now_unix_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_query_round_trip() {
+        let dir = std::env::temp_dir().join(format!("gastown-history-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = HistoryStore::open(dir.join("dashboard.db")).unwrap();
+
+        store
+            .record(HistoryMetric::AgentContextFill, "agent-1", 0.25)
+            .unwrap();
+        store
+            .record(HistoryMetric::AgentContextFill, "agent-1", 0.5)
+            .unwrap();
+        store
+            .record(HistoryMetric::ConvoyProgress, "convoy-1", 0.9)
+            .unwrap();
+
+        let samples = store
+            .history(HistoryMetric::AgentContextFill, "agent-1", Duration::from_secs(3600))
+            .unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].value, 0.25);
+        assert_eq!(samples[1].value, 0.5);
+
+        let convoy_samples = store
+            .history(HistoryMetric::ConvoyProgress, "convoy-1", Duration::from_secs(3600))
+            .unwrap();
+        assert_eq!(convoy_samples.len(), 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent_on_existing_db() {
+        let dir = std::env::temp_dir().join(format!("gastown-history-test-migrate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("dashboard.db");
+
+        let first = HistoryStore::open(&path).unwrap();
+        first
+            .record(HistoryMetric::AgentContextFill, "agent-1", 0.1)
+            .unwrap();
+        drop(first);
+
+        let reopened = HistoryStore::open(&path).unwrap();
+        let samples = reopened
+            .history(HistoryMetric::AgentContextFill, "agent-1", Duration::from_secs(3600))
+            .unwrap();
+        assert_eq!(samples.len(), 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
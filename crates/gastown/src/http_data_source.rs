@@ -0,0 +1,68 @@
+#![allow(dead_code)]
+
+use crate::dashboard_buffer::{DashboardData, DashboardDataSource, DashboardError};
+
+/// Data source that fetches dashboard state from a running Gastown daemon
+/// over HTTP, mirroring the PTTH relay pattern: the UI is a thin client that
+/// connects to a backend process forwarding live state, rather than reading
+/// it directly.
+pub struct HttpDataSource {
+    base_url: String,
+    api_key: Option<String>,
+    client: reqwest::blocking::Client,
+}
+
+impl HttpDataSource {
+    /// Creates a source pointed at `base_url` (e.g. `http://localhost:4242`),
+    /// authenticating with `api_key` if the daemon requires one.
+    pub fn new(base_url: impl Into<String>, api_key: Option<impl Into<String>>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: api_key.map(Into::into),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url.trim_end_matches('/'))
+    }
+
+    fn authenticate(
+        &self,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::blocking::RequestBuilder {
+        match &self.api_key {
+            Some(api_key) => builder.bearer_auth(api_key),
+            None => builder,
+        }
+    }
+}
+
+impl DashboardDataSource for HttpDataSource {
+    fn fetch(&self) -> Result<DashboardData, DashboardError> {
+        let response = self
+            .authenticate(self.client.get(self.url("/api/v1/dashboard")))
+            .send()
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DashboardError::FetchFailed(format!(
+                "unexpected status {}",
+                response.status()
+            )));
+        }
+
+        let body = response
+            .text()
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        DashboardData::from_json(&body)
+    }
+
+    fn is_available(&self) -> bool {
+        self.authenticate(self.client.head(self.url("/api/v1/health")))
+            .send()
+            .map(|resp| resp.status().is_success())
+            .unwrap_or(false)
+    }
+}
@@ -2,15 +2,31 @@ use anyhow::Result;
 use gpui::{App, Application, Bounds, WindowBounds, WindowOptions, actions, prelude::*, px, size};
 use std::sync::Arc;
 
+mod admin_server;
 mod agent_section;
+mod convoy_operation;
 mod convoy_section;
 mod dashboard_buffer;
+mod fuzzy;
+mod history;
+mod history_store;
+mod http_data_source;
+mod notifications;
+mod report;
 mod rig_section;
+mod storybook;
+mod subprocess_data_source;
+mod tokenizer;
+mod trace;
 
 use dashboard_buffer::{
     AgentInfo, AgentStatus, ConvoyInfo, DashboardData, DashboardDataSource, DashboardError,
     DashboardView, RigInfo, TokenUsage,
 };
+use history_store::HistoryStore;
+use http_data_source::HttpDataSource;
+use notifications::WebhookSink;
+use subprocess_data_source::SubprocessDataSource;
 
 #[cfg(test)]
 mod dashboard_buffer_tests;
@@ -34,8 +50,10 @@ impl SampleDataSource {
                     token_usage: Some(TokenUsage {
                         input_tokens: 45_230,
                         output_tokens: 12_450,
+                        model: Some("gpt-4o".into()),
                     }),
-                    context_fill: Some(0.73),
+                    context_fill: context_fill_for("gpt-4o", 45_230, 12_450),
+                    token_usage_history: vec![38_420, 42_110, 47_900, 51_600, 57_680],
                 },
                 AgentInfo {
                     name: "GreenCastle".into(),
@@ -43,28 +61,34 @@ impl SampleDataSource {
                     token_usage: Some(TokenUsage {
                         input_tokens: 8_120,
                         output_tokens: 2_340,
+                        model: Some("claude-3-5-sonnet-20241022".into()),
                     }),
-                    context_fill: Some(0.15),
+                    context_fill: context_fill_for("claude-3-5-sonnet-20241022", 8_120, 2_340),
+                    token_usage_history: vec![9_200, 9_800, 10_100, 10_460],
                 },
                 AgentInfo {
                     name: "RedMountain".into(),
                     status: AgentStatus::Error("Connection timeout".into()),
                     token_usage: None,
                     context_fill: None,
+                    token_usage_history: vec![],
                 },
             ],
             convoys: vec![
                 ConvoyInfo {
                     id: "refactor-auth".into(),
                     progress: 0.65,
+                    operation: None,
                 },
                 ConvoyInfo {
                     id: "migrate-db".into(),
                     progress: 0.30,
+                    operation: None,
                 },
                 ConvoyInfo {
                     id: "add-tests".into(),
                     progress: 0.95,
+                    operation: None,
                 },
             ],
             rigs: vec![
@@ -81,6 +105,25 @@ impl SampleDataSource {
     }
 }
 
+/// Derives `context_fill` from `tokenizer::estimate` instead of a hand-typed
+/// fraction. The sample source has no real conversation transcript to count
+/// tokens over, so it stands in placeholder text sized to roughly match the
+/// reported token counts; a real data source would pass the actual
+/// transcript it fetched.
+fn context_fill_for(model: &str, input_tokens: u64, output_tokens: u64) -> Option<f32> {
+    let conversation_text = placeholder_conversation_text(input_tokens + output_tokens);
+    tokenizer::estimate(model, &conversation_text, input_tokens, output_tokens)
+        .map(|estimate| estimate.context_fill)
+}
+
+fn placeholder_conversation_text(approx_tokens: u64) -> String {
+    // `BpeTokenizer::cl100k_like` encodes one repeat of this phrase as 49 tokens.
+    const TOKENS_PER_REPEAT: u64 = 49;
+    "the quick brown fox jumps over the lazy dog and it runs ".repeat(
+        (approx_tokens / TOKENS_PER_REPEAT).max(1) as usize,
+    )
+}
+
 impl DashboardDataSource for SampleDataSource {
     fn fetch(&self) -> Result<DashboardData, DashboardError> {
         Ok(Self::sample_data())
@@ -91,8 +134,55 @@ impl DashboardDataSource for SampleDataSource {
     }
 }
 
+/// Picks the live `DashboardDataSource` based on `GASTOWN_DATA_SOURCE`
+/// (`subprocess` or `http`), mirroring the env-var-gated selection already
+/// used for the admin server, report mode, and storybook. Falls back to
+/// [`SampleDataSource`] when unset or misconfigured.
+fn build_data_source() -> Arc<dyn DashboardDataSource> {
+    match std::env::var("GASTOWN_DATA_SOURCE").as_deref() {
+        Ok("subprocess") => {
+            let command =
+                std::env::var("GASTOWN_SUBPROCESS_COMMAND").unwrap_or_else(|_| "gt".into());
+            let args = std::env::var("GASTOWN_SUBPROCESS_ARGS")
+                .map(|raw| raw.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default();
+            Arc::new(SubprocessDataSource::new(command, args))
+        }
+        Ok("http") => match std::env::var("GASTOWN_HTTP_URL") {
+            Ok(base_url) => {
+                let api_key = std::env::var("GASTOWN_HTTP_API_KEY").ok();
+                Arc::new(HttpDataSource::new(base_url, api_key))
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "GASTOWN_DATA_SOURCE=http requires GASTOWN_HTTP_URL; falling back to sample data"
+                );
+                Arc::new(SampleDataSource)
+            }
+        },
+        Ok(other) => {
+            tracing::warn!(value = %other, "unrecognized GASTOWN_DATA_SOURCE; falling back to sample data");
+            Arc::new(SampleDataSource)
+        }
+        Err(_) => Arc::new(SampleDataSource),
+    }
+}
+
 fn main() -> Result<()> {
-    env_logger::init();
+    if std::env::var("GASTOWN_LOG_PRETTY").is_ok() {
+        trace::Trace::Pretty.init();
+    } else {
+        trace::Trace::Compact.init();
+    }
+
+    if report::dry_run_requested() || std::env::var("GASTOWN_REPORT_URL").is_ok() {
+        return capture_report();
+    }
+
+    if storybook::requested() {
+        storybook::run();
+        return Ok(());
+    }
 
     Application::new().run(|cx: &mut App| {
         cx.activate(true);
@@ -101,7 +191,18 @@ fn main() -> Result<()> {
         let size = size(px(800.), px(600.));
         let bounds = Bounds::centered(None, size, cx);
 
-        let data_source: Arc<dyn DashboardDataSource> = Arc::new(SampleDataSource);
+        let data_source = build_data_source();
+
+        if let Ok(addr) = std::env::var("GASTOWN_ADMIN_ADDR") {
+            if let Err(err) = admin_server::spawn(addr, data_source.clone()) {
+                tracing::warn!(error = %err, "failed to start admin HTTP server");
+            }
+        }
+
+        let refresh_interval = std::env::var("GASTOWN_REFRESH_INTERVAL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs);
 
         cx.open_window(
             WindowOptions {
@@ -112,10 +213,64 @@ fn main() -> Result<()> {
                 }),
                 ..Default::default()
             },
-            |_, cx| cx.new(|cx| DashboardView::new(data_source, cx)),
+            |_, cx| {
+                cx.new(|cx| {
+                    let mut view = DashboardView::new(data_source, cx);
+
+                    if let Ok(path) = std::env::var("GASTOWN_HISTORY_DB") {
+                        match HistoryStore::open(path) {
+                            Ok(store) => view.set_history_store(Arc::new(store)),
+                            Err(err) => {
+                                tracing::warn!(error = %err, "failed to open history store")
+                            }
+                        }
+                    }
+
+                    if let Ok(url) = std::env::var("GASTOWN_WEBHOOK_URL") {
+                        view.add_notification_sink(Arc::new(WebhookSink::new(url)));
+                    }
+
+                    if refresh_interval.is_some() {
+                        view.set_refresh_interval(refresh_interval, cx);
+                    }
+
+                    view
+                })
+            },
         )
         .expect("Failed to open window");
     });
 
     Ok(())
 }
+
+/// Runs the report-capture path instead of opening the dashboard window:
+/// snapshots [`SampleDataSource`]'s current data plus host environment info,
+/// then either uploads it (`GASTOWN_REPORT_URL`, with an optional
+/// `GASTOWN_REPORT_API_KEY`) or writes it to disk under `--dry-run`
+/// (`GASTOWN_REPORT_DIR`, defaulting to `./gastown-reports`).
+fn capture_report() -> Result<()> {
+    let data_source = SampleDataSource;
+    let data = data_source.fetch()?;
+    let report = report::DashboardReport::capture(data, now_unix_millis());
+
+    let client = if report::dry_run_requested() {
+        let output_dir = std::env::var("GASTOWN_REPORT_DIR").unwrap_or_else(|_| "./gastown-reports".into());
+        report::ReportClient::dry(output_dir)
+    } else {
+        let url = std::env::var("GASTOWN_REPORT_URL").expect("checked by caller");
+        let api_key = std::env::var("GASTOWN_REPORT_API_KEY").ok();
+        report::ReportClient::remote(url, api_key)
+    };
+
+    let location = client.submit(&report)?;
+    println!("dashboard report captured: {location}");
+    Ok(())
+}
+
+fn now_unix_millis() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0)
+}
@@ -0,0 +1,236 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::dashboard_buffer::DashboardEvent;
+
+/// Kind of [`DashboardEvent`] a [`NotificationSink`] can be asked to forward.
+///
+/// Mirrors the variants of `DashboardEvent` minus their payloads, so callers
+/// can filter which events get forwarded without matching on the event itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    DataRefreshed,
+    ConnectionChanged,
+    AgentAdded,
+    AgentRemoved,
+    AgentStatusChanged,
+    ConvoyCompleted,
+}
+
+impl NotificationKind {
+    fn of(event: &DashboardEvent) -> Self {
+        match event {
+            DashboardEvent::DataRefreshed => NotificationKind::DataRefreshed,
+            DashboardEvent::ConnectionChanged(_) => NotificationKind::ConnectionChanged,
+            DashboardEvent::AgentAdded(_) => NotificationKind::AgentAdded,
+            DashboardEvent::AgentRemoved(_) => NotificationKind::AgentRemoved,
+            DashboardEvent::AgentStatusChanged { .. } => NotificationKind::AgentStatusChanged,
+            DashboardEvent::ConvoyCompleted(_) => NotificationKind::ConvoyCompleted,
+        }
+    }
+
+    /// Stable wire name used as the `event` field of a [`WebhookTemplate::Structured`] payload.
+    fn as_str(self) -> &'static str {
+        match self {
+            NotificationKind::DataRefreshed => "data_refreshed",
+            NotificationKind::ConnectionChanged => "connection_changed",
+            NotificationKind::AgentAdded => "agent_added",
+            NotificationKind::AgentRemoved => "agent_removed",
+            NotificationKind::AgentStatusChanged => "agent_status_changed",
+            NotificationKind::ConvoyCompleted => "convoy_completed",
+        }
+    }
+}
+
+/// Destination for forwarded dashboard events.
+pub trait NotificationSink: Send + Sync {
+    /// Sends a notification for `event`. Called from a background task (see
+    /// [`forward`]), so implementations may block on I/O; failures are
+    /// swallowed (logged) rather than propagated into the refresh path.
+    fn notify(&self, event: &DashboardEvent);
+}
+
+/// Renders a templated, human-readable message for a forwarded event.
+///
+/// Agent and connection statuses are rendered in their `Debug` form (e.g.
+/// `Error("Connection timeout")`), not the in-app `●`/`○`/`✗` glyphs.
+fn render_message(event: &DashboardEvent) -> String {
+    match event {
+        DashboardEvent::DataRefreshed => "📣 dashboard refreshed".to_string(),
+        DashboardEvent::ConnectionChanged(status) => {
+            format!("📣 connection → {status:?}")
+        }
+        DashboardEvent::AgentAdded(name) => format!("📣 agent `{name}` added"),
+        DashboardEvent::AgentRemoved(name) => format!("📣 agent `{name}` removed"),
+        DashboardEvent::AgentStatusChanged { name, status } => {
+            format!("📣 agent `{name}` → {status:?}")
+        }
+        DashboardEvent::ConvoyCompleted(id) => format!("📣 convoy `{id}` completed"),
+    }
+}
+
+/// Extracts a `(name, detail)` pair for [`WebhookTemplate::Structured`]'s
+/// `name`/`detail` fields.
+fn event_name_and_detail(event: &DashboardEvent) -> (String, String) {
+    match event {
+        DashboardEvent::DataRefreshed => ("dashboard".to_string(), "refreshed".to_string()),
+        DashboardEvent::ConnectionChanged(status) => ("dashboard".to_string(), format!("{status:?}")),
+        DashboardEvent::AgentAdded(name) => (name.clone(), "added".to_string()),
+        DashboardEvent::AgentRemoved(name) => (name.clone(), "removed".to_string()),
+        DashboardEvent::AgentStatusChanged { name, status } => (name.clone(), format!("{status:?}")),
+        DashboardEvent::ConvoyCompleted(id) => (id.clone(), "completed".to_string()),
+    }
+}
+
+fn now_unix_millis() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+/// Shape of the JSON body [`WebhookSink`] posts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookTemplate {
+    /// `{"content": "..."}`, the shape understood by Discord's (and
+    /// Slack-compatible) incoming webhooks.
+    DiscordContent,
+    /// `{"event", "name", "detail", "timestamp"}`, a generic structured
+    /// payload for endpoints that parse JSON themselves rather than
+    /// rendering a chat message.
+    Structured,
+}
+
+/// Posts a JSON payload to a configured webhook URL for a filterable subset
+/// of dashboard events.
+pub struct WebhookSink {
+    url: String,
+    client: reqwest::blocking::Client,
+    forwarded_kinds: HashSet<NotificationKind>,
+    template: WebhookTemplate,
+}
+
+impl WebhookSink {
+    /// Creates a sink that forwards every event kind as a Discord-compatible
+    /// `{content}` body.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: reqwest::blocking::Client::new(),
+            forwarded_kinds: [
+                NotificationKind::DataRefreshed,
+                NotificationKind::ConnectionChanged,
+                NotificationKind::AgentAdded,
+                NotificationKind::AgentRemoved,
+                NotificationKind::AgentStatusChanged,
+                NotificationKind::ConvoyCompleted,
+            ]
+            .into_iter()
+            .collect(),
+            template: WebhookTemplate::DiscordContent,
+        }
+    }
+
+    /// Restricts forwarding to the given event kinds, e.g. to avoid getting
+    /// spammed by every `DataRefreshed` tick.
+    pub fn filtered(mut self, kinds: impl IntoIterator<Item = NotificationKind>) -> Self {
+        self.forwarded_kinds = kinds.into_iter().collect();
+        self
+    }
+
+    /// Switches the POST body shape. Defaults to [`WebhookTemplate::DiscordContent`].
+    pub fn with_template(mut self, template: WebhookTemplate) -> Self {
+        self.template = template;
+        self
+    }
+
+    fn should_forward(&self, event: &DashboardEvent) -> bool {
+        self.forwarded_kinds.contains(&NotificationKind::of(event))
+    }
+
+    fn payload(&self, event: &DashboardEvent) -> serde_json::Value {
+        match self.template {
+            WebhookTemplate::DiscordContent => {
+                serde_json::json!({ "content": render_message(event) })
+            }
+            WebhookTemplate::Structured => {
+                let (name, detail) = event_name_and_detail(event);
+                serde_json::json!({
+                    "event": NotificationKind::of(event).as_str(),
+                    "name": name,
+                    "detail": detail,
+                    "timestamp": now_unix_millis(),
+                })
+            }
+        }
+    }
+}
+
+impl NotificationSink for WebhookSink {
+    fn notify(&self, event: &DashboardEvent) {
+        if !self.should_forward(event) {
+            return;
+        }
+
+        let payload = self.payload(event);
+
+        // Best-effort delivery: failures are logged, never propagated into
+        // the refresh path that triggered this notification.
+        if let Err(err) = self.client.post(&self.url).json(&payload).send() {
+            tracing::warn!(url = %self.url, error = %err, "webhook delivery failed");
+        }
+    }
+}
+
+/// Forwards an event to every configured sink, off the UI thread.
+///
+/// `DashboardView` calls this from a background task so a slow or unreachable
+/// webhook can never stall a refresh.
+pub fn forward(sinks: &[Arc<dyn NotificationSink>], event: &DashboardEvent) {
+    for sink in sinks {
+        sink.notify(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_kind_of_matches_event() {
+        assert_eq!(
+            NotificationKind::of(&DashboardEvent::DataRefreshed),
+            NotificationKind::DataRefreshed
+        );
+        assert_eq!(
+            NotificationKind::of(&DashboardEvent::AgentAdded("a".into())),
+            NotificationKind::AgentAdded
+        );
+    }
+
+    #[test]
+    fn test_render_message_includes_agent_name() {
+        let message = render_message(&DashboardEvent::AgentAdded("BlueLake".into()));
+        assert!(message.contains("BlueLake"));
+    }
+
+    #[test]
+    fn test_discord_template_wraps_message_in_content_field() {
+        let sink = WebhookSink::new("https://example.com/hook");
+        let payload = sink.payload(&DashboardEvent::ConvoyCompleted("convoy-1".into()));
+        assert!(payload["content"].as_str().unwrap().contains("convoy-1"));
+    }
+
+    #[test]
+    fn test_structured_template_includes_event_name_and_detail() {
+        let sink =
+            WebhookSink::new("https://example.com/hook").with_template(WebhookTemplate::Structured);
+        let payload = sink.payload(&DashboardEvent::ConvoyCompleted("convoy-1".into()));
+        assert_eq!(payload["event"], "convoy_completed");
+        assert_eq!(payload["name"], "convoy-1");
+        assert_eq!(payload["detail"], "completed");
+        assert!(payload["timestamp"].is_u64());
+    }
+}
@@ -0,0 +1,200 @@
+#![allow(dead_code)]
+
+//! Dry-run benchmark/report capture: snapshots the current [`DashboardData`]
+//! plus host environment info into a self-contained JSON report, either
+//! uploading it to a collector or writing it to disk. Gives users a
+//! reproducible capture of agent/convoy state to attach to bug reports or
+//! diff offline across a session, without requiring a live daemon connection.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dashboard_buffer::{DashboardData, DashboardError};
+
+/// Host environment captured alongside a [`DashboardReport`], so a report
+/// opened later (or on another machine) carries enough context to explain
+/// what produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentInfo {
+    pub hostname: String,
+    pub os: String,
+    pub cpu_count: usize,
+    /// Unix epoch milliseconds when the report was captured.
+    pub captured_at_unix_millis: u128,
+}
+
+impl EnvironmentInfo {
+    /// Captures the current process's host environment.
+    pub fn capture(captured_at_unix_millis: u128) -> Self {
+        Self {
+            hostname: Self::hostname(),
+            os: std::env::consts::OS.to_string(),
+            cpu_count: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            captured_at_unix_millis,
+        }
+    }
+
+    fn hostname() -> String {
+        std::env::var("HOSTNAME")
+            .or_else(|_| std::env::var("COMPUTERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string())
+    }
+}
+
+/// A self-contained, reproducible snapshot of the dashboard: its data plus
+/// the environment that captured it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardReport {
+    pub data: DashboardData,
+    pub environment: EnvironmentInfo,
+}
+
+impl DashboardReport {
+    pub fn capture(data: DashboardData, captured_at_unix_millis: u128) -> Self {
+        Self {
+            data,
+            environment: EnvironmentInfo::capture(captured_at_unix_millis),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, DashboardError> {
+        serde_json::to_string_pretty(self).map_err(|e| DashboardError::ParseError(e.to_string()))
+    }
+}
+
+/// Where a captured [`DashboardReport`] goes once generated: uploaded to a
+/// collector, or written to disk with no network call at all. Both variants
+/// share the same `submit` method so call sites don't need to branch on
+/// `--dry-run` themselves.
+pub enum ReportClient {
+    Remote {
+        url: String,
+        api_key: Option<String>,
+        client: reqwest::blocking::Client,
+    },
+    Dry {
+        output_dir: PathBuf,
+    },
+}
+
+impl ReportClient {
+    /// A client that uploads reports to `url`, authenticating with
+    /// `api_key` if the collector requires one.
+    pub fn remote(url: impl Into<String>, api_key: Option<impl Into<String>>) -> Self {
+        Self::Remote {
+            url: url.into(),
+            api_key: api_key.map(Into::into),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// A client that writes reports under `output_dir` and never touches
+    /// the network, for the `--dry-run` path.
+    pub fn dry(output_dir: impl Into<PathBuf>) -> Self {
+        Self::Dry {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Submits `report`, returning where it ended up: the collector URL it
+    /// was posted to for `Remote`, the file path written for `Dry`.
+    pub fn submit(&self, report: &DashboardReport) -> Result<String, DashboardError> {
+        match self {
+            ReportClient::Remote {
+                url,
+                api_key,
+                client,
+            } => Self::submit_remote(client, url, api_key.as_deref(), report),
+            ReportClient::Dry { output_dir } => Self::submit_dry(output_dir, report),
+        }
+    }
+
+    fn submit_remote(
+        client: &reqwest::blocking::Client,
+        url: &str,
+        api_key: Option<&str>,
+        report: &DashboardReport,
+    ) -> Result<String, DashboardError> {
+        let json = report.to_json()?;
+        let mut builder = client.post(url).header("Content-Type", "application/json");
+        if let Some(api_key) = api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+
+        let response = builder
+            .body(json)
+            .send()
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DashboardError::FetchFailed(format!(
+                "unexpected status {}",
+                response.status()
+            )));
+        }
+
+        Ok(url.to_string())
+    }
+
+    fn submit_dry(output_dir: &Path, report: &DashboardReport) -> Result<String, DashboardError> {
+        std::fs::create_dir_all(output_dir)
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        let path = output_dir.join(format!(
+            "dashboard-report-{}.json",
+            report.environment.captured_at_unix_millis
+        ));
+        std::fs::write(&path, report.to_json()?)
+            .map_err(|e| DashboardError::FetchFailed(e.to_string()))?;
+
+        Ok(path.display().to_string())
+    }
+}
+
+/// Whether the process was launched with `--dry-run`, the switch that makes
+/// report capture local-only (no upload, no network).
+pub fn dry_run_requested() -> bool {
+    std::env::args().any(|arg| arg == "--dry-run")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dashboard_buffer::DashboardData;
+
+    fn empty_data() -> DashboardData {
+        DashboardData {
+            agents: vec![],
+            convoys: vec![],
+            rigs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_dry_submit_writes_json_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "gastown-report-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let report = DashboardReport::capture(empty_data(), 1_700_000_000_000);
+        let client = ReportClient::dry(dir.clone());
+        let path = client.submit(&report).expect("dry submit should succeed");
+
+        let written = std::fs::read_to_string(&path).expect("report file should exist");
+        assert!(written.contains("\"hostname\""));
+        assert!(written.contains("\"captured_at_unix_millis\": 1700000000000"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_environment_capture_reports_at_least_one_cpu() {
+        let env = EnvironmentInfo::capture(0);
+        assert!(env.cpu_count >= 1);
+    }
+}
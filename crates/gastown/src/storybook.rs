@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+
+//! Standalone preview window enumerating `AgentSection`/`AgentRow`/`ContextBar`
+//! states side by side, so contributors can eyeball styling changes without
+//! wiring up a full dashboard. Not a Cargo feature (this crate's workspace
+//! has none) - entered the same way [`crate::capture_report`] is, via an env
+//! var checked in `main` before the normal dashboard window opens.
+
+use gpui::{
+    App, Application, Bounds, Focusable, FocusHandle, IntoElement, ParentElement, Render, Styled,
+    Window, WindowBounds, WindowOptions, div, px, size,
+};
+use ui::ActiveTheme;
+
+use crate::agent_section::{AgentRow, AgentSection, AgentSectionPalette, ContextBar};
+use crate::dashboard_buffer::{AgentInfo, AgentStatus, TokenUsage};
+
+/// Env var that selects the storybook window instead of the normal
+/// dashboard window when set to any value.
+pub const STORYBOOK_ENV_VAR: &str = "GASTOWN_STORYBOOK";
+
+pub fn requested() -> bool {
+    std::env::var(STORYBOOK_ENV_VAR).is_ok()
+}
+
+/// Opens a window that renders every representative `AgentSection` state:
+/// an empty list, collapsed and expanded, each `AgentStatus` variant
+/// (including a visible `Error` message), context fills at 0/50/85/100%,
+/// and rows with and without `token_usage`.
+pub fn run() {
+    Application::new().run(|cx: &mut App| {
+        cx.activate(true);
+
+        let size = size(px(700.), px(900.));
+        let bounds = Bounds::centered(None, size, cx);
+
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                titlebar: Some(gpui::TitlebarOptions {
+                    title: Some("Gas Town Storybook".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            |_, cx| cx.new(Storybook::new),
+        )
+        .expect("Failed to open storybook window");
+    });
+}
+
+fn sample_agents() -> Vec<AgentInfo> {
+    vec![
+        AgentInfo {
+            name: "ActiveAgent".into(),
+            status: AgentStatus::Active,
+            token_usage: Some(TokenUsage {
+                input_tokens: 45_230,
+                output_tokens: 12_450,
+                model: Some("gpt-4o".into()),
+            }),
+            context_fill: Some(0.5),
+            token_usage_history: vec![10_000, 22_000, 31_000, 45_230],
+        },
+        AgentInfo {
+            name: "IdleAgent".into(),
+            status: AgentStatus::Idle,
+            token_usage: None,
+            context_fill: None,
+            token_usage_history: vec![],
+        },
+        AgentInfo {
+            name: "ErroredAgent".into(),
+            status: AgentStatus::Error("Connection timeout".into()),
+            token_usage: Some(TokenUsage {
+                input_tokens: 900,
+                output_tokens: 120,
+                model: None,
+            }),
+            context_fill: Some(1.0),
+            token_usage_history: vec![400, 900],
+        },
+    ]
+}
+
+struct Storybook {
+    focus_handle: FocusHandle,
+}
+
+impl Storybook {
+    fn new(cx: &mut gpui::Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    fn render_section(
+        &self,
+        title: &'static str,
+        agents: &[AgentInfo],
+        expanded: bool,
+        palette: AgentSectionPalette,
+    ) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child(div().text_sm().child(title))
+            .child(AgentSection::new(agents, palette).expanded(expanded))
+    }
+
+    fn render_context_bars(&self, palette: AgentSectionPalette) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child("ContextBar at 0 / 50 / 85 / 100%")
+            .child(
+                div()
+                    .flex()
+                    .gap(px(12.0))
+                    .child(ContextBar::new(0.0, palette))
+                    .child(ContextBar::new(0.5, palette))
+                    .child(ContextBar::new(0.85, palette))
+                    .child(ContextBar::new(1.0, palette)),
+            )
+    }
+
+    fn render_agent_rows(&self, palette: AgentSectionPalette) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child("AgentRow per status, with and without token_usage")
+            .children(sample_agents().into_iter().map(|agent| {
+                AgentRow::new(agent, palette, false).into_any_element()
+            }))
+    }
+}
+
+impl Focusable for Storybook {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Storybook {
+    fn render(&mut self, _window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let palette = AgentSectionPalette::from_theme(cx);
+        let agents = sample_agents();
+        let empty: Vec<AgentInfo> = vec![];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(16.0))
+            .p(px(16.0))
+            .bg(cx.theme().colors().panel_background)
+            .child(self.render_section("Empty", &empty, true, palette))
+            .child(self.render_section("Collapsed", &agents, false, palette))
+            .child(self.render_section("Expanded", &agents, true, palette))
+            .child(self.render_context_bars(palette))
+            .child(self.render_agent_rows(palette))
+    }
+}
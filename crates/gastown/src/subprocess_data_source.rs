@@ -0,0 +1,305 @@
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::dashboard_buffer::{AgentStatus, DashboardData, DashboardDataSource, DashboardError};
+
+/// An incremental update applied to a cached `DashboardData` snapshot.
+///
+/// Mirrors the shape of messages emitted by the `gt` subprocess adapter: either
+/// a full snapshot, or a targeted patch to a single agent's status.
+#[derive(Debug)]
+enum AdapterMessage {
+    Snapshot(DashboardData),
+    AgentStatus { name: String, status: AgentStatus },
+}
+
+impl AdapterMessage {
+    /// Parses a single newline-framed JSON line from the adapter.
+    ///
+    /// The `agentStatus` patch is picked out with a small hand-rolled field
+    /// extractor to avoid deserializing the whole line twice; anything else
+    /// is decoded as a full `DashboardData` snapshot via `serde_json`.
+    fn parse(line: &str) -> Result<Self, DashboardError> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Err(DashboardError::ParseError("empty adapter line".into()));
+        }
+
+        if line.contains("\"type\":\"agentStatus\"") || line.contains("\"type\": \"agentStatus\"") {
+            let name = extract_field(line, "name")
+                .ok_or_else(|| DashboardError::ParseError("missing agent name".into()))?;
+            let status_raw = extract_field(line, "status")
+                .ok_or_else(|| DashboardError::ParseError("missing agent status".into()))?;
+            let status = match status_raw.as_str() {
+                "active" => AgentStatus::Active,
+                "idle" => AgentStatus::Idle,
+                other => AgentStatus::Error(other.to_string()),
+            };
+            Ok(AdapterMessage::AgentStatus { name, status })
+        } else {
+            // Anything else is treated as a full snapshot.
+            DashboardData::from_json(line).map(AdapterMessage::Snapshot)
+        }
+    }
+}
+
+/// Extracts `"field":"value"` from a single-line JSON object without pulling
+/// in a JSON parser. Deliberately tolerant of single or no spaces after the colon.
+fn extract_field(line: &str, field: &str) -> Option<String> {
+    let needle = format!("\"{field}\"");
+    let start = line.find(&needle)? + needle.len();
+    let rest = &line[start..];
+    let colon = rest.find(':')? + 1;
+    let rest = rest[colon..].trim_start();
+    let rest = rest.strip_prefix('"')?;
+    let end = rest.find('"')?;
+    Some(rest[..end].to_string())
+}
+
+/// Backoff schedule used when restarting a crashed subprocess.
+struct Backoff {
+    attempt: u32,
+}
+
+impl Backoff {
+    fn new() -> Self {
+        Self { attempt: 0 }
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let millis = 200u64.saturating_mul(1u64 << self.attempt.min(5));
+        self.attempt += 1;
+        Duration::from_millis(millis.min(10_000))
+    }
+
+    fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+struct AdapterProcess {
+    child: Child,
+    stdin: ChildStdin,
+    /// Complete newline-framed lines read off `child`'s stdout by a dedicated
+    /// reader thread, which blocks on each `read_line` so `pump_messages`
+    /// never has to.
+    lines: Receiver<String>,
+}
+
+/// Data source that talks to a long-lived `gt` subprocess over line-delimited
+/// JSON on stdio, in the spirit of a debug-adapter launcher: spawn once,
+/// subscribe, then keep reading snapshot/patch messages until the pipe closes.
+pub struct SubprocessDataSource {
+    command: String,
+    args: Vec<String>,
+    process: Mutex<Option<AdapterProcess>>,
+    cached: Mutex<Option<DashboardData>>,
+    backoff: Mutex<Backoff>,
+    last_restart_attempt: Mutex<Option<Instant>>,
+    alive: AtomicBool,
+}
+
+impl SubprocessDataSource {
+    pub fn new(command: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            command: command.into(),
+            args,
+            process: Mutex::new(None),
+            cached: Mutex::new(None),
+            backoff: Mutex::new(Backoff::new()),
+            last_restart_attempt: Mutex::new(None),
+            alive: AtomicBool::new(false),
+        }
+    }
+
+    /// Spawns the adapter process and sends the initial subscribe request.
+    fn spawn(&self) -> Result<AdapterProcess, DashboardError> {
+        let mut child = Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| DashboardError::FetchFailed(format!("failed to spawn '{}': {e}", self.command)))?;
+
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| DashboardError::FetchFailed("adapter stdin unavailable".into()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| DashboardError::FetchFailed("adapter stdout unavailable".into()))?;
+
+        stdin
+            .write_all(b"{\"command\":\"subscribe\"}\n")
+            .map_err(|e| DashboardError::FetchFailed(format!("failed to send subscribe: {e}")))?;
+
+        self.alive.store(true, Ordering::SeqCst);
+
+        let (tx, rx) = channel();
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(stdout);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if tx.send(std::mem::take(&mut line)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(AdapterProcess {
+            child,
+            stdin,
+            lines: rx,
+        })
+    }
+
+    /// Ensures a live adapter process exists, restarting with backoff if the
+    /// previous one exited.
+    fn ensure_process(&self) -> Result<(), DashboardError> {
+        let mut guard = self.process.lock().unwrap();
+
+        if let Some(proc) = guard.as_mut() {
+            if let Ok(Some(_status)) = proc.child.try_wait() {
+                *guard = None;
+                self.alive.store(false, Ordering::SeqCst);
+            } else {
+                return Ok(());
+            }
+        }
+
+        let mut last_attempt = self.last_restart_attempt.lock().unwrap();
+        let mut backoff = self.backoff.lock().unwrap();
+        if let Some(last) = *last_attempt {
+            let delay = backoff.next_delay();
+            if last.elapsed() < delay {
+                return Err(DashboardError::NotAvailable);
+            }
+        }
+        *last_attempt = Some(Instant::now());
+
+        let spawned = self.spawn()?;
+        backoff.reset();
+        *guard = Some(spawned);
+        Ok(())
+    }
+
+    /// Applies every complete message currently buffered on the reader
+    /// thread's channel, then returns without waiting for more. The adapter
+    /// is a long-lived subscription, so blocking here for the *next* message
+    /// would wedge the caller (the background-executor thread driving
+    /// `set_refresh_interval`) forever after the first message.
+    fn pump_messages(&self) -> Result<(), DashboardError> {
+        let mut guard = self.process.lock().unwrap();
+        let proc = guard
+            .as_mut()
+            .ok_or(DashboardError::NotAvailable)?;
+
+        loop {
+            match proc.lines.try_recv() {
+                Ok(line) => match AdapterMessage::parse(&line) {
+                    Ok(AdapterMessage::Snapshot(data)) => {
+                        *self.cached.lock().unwrap() = Some(data);
+                    }
+                    Ok(AdapterMessage::AgentStatus { name, status }) => {
+                        if let Some(data) = self.cached.lock().unwrap().as_mut() {
+                            if let Some(agent) = data.agents.iter_mut().find(|a| a.name == name) {
+                                agent.status = status;
+                            }
+                        }
+                    }
+                    Err(err) => return Err(err),
+                },
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // Reader thread exited: the child's stdout closed.
+                    *guard = None;
+                    self.alive.store(false, Ordering::SeqCst);
+                    return Err(DashboardError::NotAvailable);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DashboardDataSource for SubprocessDataSource {
+    fn fetch(&self) -> Result<DashboardData, DashboardError> {
+        self.ensure_process()?;
+        self.pump_messages()?;
+
+        self.cached
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| DashboardError::FetchFailed("no snapshot received yet".into()))
+    }
+
+    fn is_available(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_field() {
+        let line = r#"{"type":"agentStatus","name":"BlueLake","status":"active"}"#;
+        assert_eq!(extract_field(line, "name"), Some("BlueLake".to_string()));
+        assert_eq!(extract_field(line, "status"), Some("active".to_string()));
+    }
+
+    #[test]
+    fn test_parse_agent_status_patch() {
+        let line = r#"{"type":"agentStatus","name":"RedMountain","status":"idle"}"#;
+        let msg = AdapterMessage::parse(line).unwrap();
+        match msg {
+            AdapterMessage::AgentStatus { name, status } => {
+                assert_eq!(name, "RedMountain");
+                assert_eq!(status, AgentStatus::Idle);
+            }
+            _ => panic!("expected agent status patch"),
+        }
+    }
+
+    #[test]
+    fn test_parse_snapshot() {
+        let line = r#"{"agents":[],"convoys":[],"rigs":[]}"#;
+        let msg = AdapterMessage::parse(line).unwrap();
+        match msg {
+            AdapterMessage::Snapshot(data) => {
+                assert!(data.agents.is_empty());
+                assert!(data.convoys.is_empty());
+                assert!(data.rigs.is_empty());
+            }
+            _ => panic!("expected snapshot"),
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_then_resets() {
+        let mut backoff = Backoff::new();
+        let first = backoff.next_delay();
+        let second = backoff.next_delay();
+        assert!(second > first);
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), first);
+    }
+}
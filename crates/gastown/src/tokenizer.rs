@@ -0,0 +1,209 @@
+//! A small byte-pair-encoding tokenizer, in the style of tiktoken, plus a
+//! per-model pricing table. Lets a data source turn raw conversation text
+//! into a real `context_fill` ratio and a dollar cost estimate instead of
+//! requiring the caller to precompute an opaque fraction.
+//!
+//! The merge table below is a compact approximation of common English
+//! subword merges, not the official cl100k vocabulary - good enough for a
+//! context-fill estimate, not for exact token accounting.
+
+/// Greedy BPE tokenizer: starts from one token per character and repeatedly
+/// applies the highest-priority merge rule that still matches, same
+/// algorithm tiktoken uses over byte pairs.
+pub struct BpeTokenizer {
+    merges: Vec<(String, String)>,
+}
+
+impl BpeTokenizer {
+    /// A tokenizer seeded with common English subword merges, used as the
+    /// default for context-fill and cost estimates across all models.
+    pub fn cl100k_like() -> Self {
+        const MERGE_PAIRS: &[(&str, &str)] = &[
+            ("t", "h"),
+            ("th", "e"),
+            ("i", "n"),
+            ("e", "r"),
+            ("a", "n"),
+            ("r", "e"),
+            ("o", "n"),
+            ("a", "t"),
+            ("e", "n"),
+            ("o", "r"),
+            ("i", "ng"),
+            ("n", "g"),
+            ("e", "d"),
+            ("i", "s"),
+            ("i", "t"),
+            ("o", "u"),
+            ("a", "l"),
+            ("s", "t"),
+            ("t", "o"),
+            ("a", "r"),
+        ];
+
+        Self {
+            merges: MERGE_PAIRS
+                .iter()
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Splits `text` into BPE tokens, merging adjacent pairs in priority
+    /// order until no merge rule applies.
+    pub fn encode(&self, text: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = text.chars().map(|c| c.to_string()).collect();
+
+        for (a, b) in &self.merges {
+            let mut i = 0;
+            while i + 1 < tokens.len() {
+                if &tokens[i] == a && &tokens[i + 1] == b {
+                    let merged = format!("{a}{b}");
+                    tokens.splice(i..=i + 1, [merged]);
+                } else {
+                    i += 1;
+                }
+            }
+        }
+
+        tokens
+    }
+
+    pub fn count_tokens(&self, text: &str) -> usize {
+        self.encode(text).len()
+    }
+}
+
+/// Context window size and per-token pricing for one known model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModelPricing {
+    pub context_window: u32,
+    pub input_price_per_million_usd: f64,
+    pub output_price_per_million_usd: f64,
+}
+
+fn model_table() -> &'static [(&'static str, ModelPricing)] {
+    &[
+        (
+            "gpt-4o",
+            ModelPricing {
+                context_window: 128_000,
+                input_price_per_million_usd: 2.50,
+                output_price_per_million_usd: 10.00,
+            },
+        ),
+        (
+            "gpt-4o-mini",
+            ModelPricing {
+                context_window: 128_000,
+                input_price_per_million_usd: 0.15,
+                output_price_per_million_usd: 0.60,
+            },
+        ),
+        (
+            "claude-3-5-sonnet-20241022",
+            ModelPricing {
+                context_window: 200_000,
+                input_price_per_million_usd: 3.00,
+                output_price_per_million_usd: 15.00,
+            },
+        ),
+        (
+            "claude-3-opus-20240229",
+            ModelPricing {
+                context_window: 200_000,
+                input_price_per_million_usd: 15.00,
+                output_price_per_million_usd: 75.00,
+            },
+        ),
+        (
+            "gemini-1.5-pro",
+            ModelPricing {
+                context_window: 1_000_000,
+                input_price_per_million_usd: 1.25,
+                output_price_per_million_usd: 5.00,
+            },
+        ),
+    ]
+}
+
+/// Looks up `model`'s context window and per-token pricing. Returns `None`
+/// for unrecognized model ids, so callers can fall back to hiding the
+/// context bar rather than showing a made-up fraction.
+pub fn model_pricing(model: &str) -> Option<ModelPricing> {
+    model_table()
+        .iter()
+        .find(|(id, _)| *id == model)
+        .map(|(_, pricing)| *pricing)
+}
+
+/// Derived context-fill ratio and cost estimate for one agent's turn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextEstimate {
+    /// Tokens used divided by the model's context window, clamped to
+    /// `[0.0, 1.0]`.
+    pub context_fill: f32,
+    pub estimated_cost_usd: f64,
+}
+
+/// Counts tokens in `conversation_text` with [`BpeTokenizer::cl100k_like`]
+/// and combines them with `input_tokens`/`output_tokens` pricing to derive
+/// a [`ContextEstimate`]. Returns `None` when `model` isn't in the pricing
+/// table.
+pub fn estimate(
+    model: &str,
+    conversation_text: &str,
+    input_tokens: u64,
+    output_tokens: u64,
+) -> Option<ContextEstimate> {
+    let pricing = model_pricing(model)?;
+    let tokenizer = BpeTokenizer::cl100k_like();
+    let used_tokens = tokenizer.count_tokens(conversation_text) as f32;
+
+    let context_fill = (used_tokens / pricing.context_window as f32).clamp(0.0, 1.0);
+    let estimated_cost_usd = (input_tokens as f64 * pricing.input_price_per_million_usd
+        + output_tokens as f64 * pricing.output_price_per_million_usd)
+        / 1_000_000.0;
+
+    Some(ContextEstimate {
+        context_fill,
+        estimated_cost_usd,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_merges_common_pairs() {
+        let tokenizer = BpeTokenizer::cl100k_like();
+        let tokens = tokenizer.encode("the");
+        assert_eq!(tokens, vec!["the".to_string()]);
+    }
+
+    #[test]
+    fn test_count_tokens_is_never_more_than_char_count() {
+        let tokenizer = BpeTokenizer::cl100k_like();
+        let text = "the tokenizer is greedy";
+        assert!(tokenizer.count_tokens(text) <= text.chars().count());
+    }
+
+    #[test]
+    fn test_model_pricing_unknown_model_returns_none() {
+        assert_eq!(model_pricing("not-a-real-model"), None);
+    }
+
+    #[test]
+    fn test_estimate_clamps_context_fill() {
+        let long_text = "a".repeat(10_000_000);
+        let estimate = estimate("gpt-4o-mini", &long_text, 0, 0).unwrap();
+        assert_eq!(estimate.context_fill, 1.0);
+    }
+
+    #[test]
+    fn test_estimate_computes_cost_from_pricing() {
+        let estimate = estimate("gpt-4o", "hello", 1_000_000, 1_000_000).unwrap();
+        assert_eq!(estimate.estimated_cost_usd, 2.50 + 10.00);
+    }
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+//! Structured logging setup for the dashboard refresh path.
+//!
+//! Two output layers are available so the same `tracing` event stream can be
+//! rendered densely in production or verbosely during development: [`Trace::Compact`]
+//! emits one line per event, [`Trace::Pretty`] spreads fields across multiple
+//! indented lines.
+
+use tracing_subscriber::fmt;
+use tracing_subscriber::prelude::*;
+
+/// Selects the output layer installed by [`Trace::init`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trace {
+    /// One line per event; suited to production logs and log aggregators.
+    Compact,
+    /// Multi-line, indented output; suited to local development.
+    Pretty,
+}
+
+impl Trace {
+    /// Installs this layer as the global default `tracing` subscriber.
+    ///
+    /// Only the first call in a process takes effect: later calls find a
+    /// global subscriber already set and are silently ignored.
+    pub fn init(self) {
+        let registry = tracing_subscriber::registry();
+        match self {
+            Trace::Compact => {
+                let _ = registry.with(fmt::layer().compact()).try_init();
+            }
+            Trace::Pretty => {
+                let _ = registry.with(fmt::layer().pretty()).try_init();
+            }
+        }
+    }
+}
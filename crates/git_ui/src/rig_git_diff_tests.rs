@@ -3,6 +3,20 @@
 //! Rigs are external git repositories managed by the overseer system.
 //! Each rig is a separate git repository that agents can work in.
 //! This module tests the integration of git UI with rig directories.
+//!
+//! NOTE: this crate currently contains only this test module. `git_panel.rs`
+//! and `file_diff_view.rs` (and the `editor`/`project`/`workspace`/`git`
+//! crates they depend on) are not present in this checkout, so the panel and
+//! diff-view behavior these tests exercise can't actually be changed here.
+//! Backlog requests chunk4-1 through chunk4-6 (batched incremental status
+//! recomputation, binary/image diff rendering, per-rig grouped sections,
+//! `.git` metadata watching, stage/unstage/revert/commit operations, and
+//! rename-aware status/diff continuity) all target types this module's own
+//! imports reference but that don't exist anywhere in this checkout -
+//! `GitPanel`, `FileDiffView`, `GitListEntry`/`Section`/`GitHeaderEntry` -
+//! so none of them are actionable here. They're recorded as not actionable
+//! rather than silently dropped; each needs the real implementation files
+//! to land in this tree before it can be applied for real.
 
 #[cfg(test)]
 mod tests {